@@ -0,0 +1,232 @@
+// 📦 derive 输入 - 从 `#[derive(BizErrorCode)]` 标注的枚举构建 ErrorCatalog
+//
+// 不需要 YAML 文件，错误码直接声明在枚举的属性上：
+//
+// ```rust,ignore
+// #[derive(Debug, Clone, Copy, PartialEq, Eq, BizErrorCode)]
+// #[biz(default_language = "en", supported_languages = ["en", "zh-CN"])]
+// enum ErrorCode {
+//     #[biz(code = 4000, http = 400, en = "INVALID PARAMETER", zh_CN = "参数无效")]
+//     InvalidParam,
+// }
+// ```
+//
+// 属性先转换为与 YAML 相同的 `BizErrorConfig`，再走同一套校验（重复错误码、翻译完整性等），
+// 因此两种方式的校验规则完全一致。
+
+use indexmap::IndexMap;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Expr, ExprArray, ExprLit, ExprUnary, Fields, Lit, LitInt, LitStr, UnOp};
+
+use crate::model::ErrorCatalog;
+use crate::schema::{BizErrorConfig, ErrorDef, MissingTranslation};
+use crate::Error;
+
+impl ErrorCatalog {
+    /// 从 `#[derive(BizErrorCode)]` 的输入构建错误码目录
+    ///
+    /// 错误名为变体名的 snake_case 形式（`InvalidParam` → `invalid_param`），
+    /// 生成的代码直接使用原变体名。
+    pub fn from_derive_input(input: &DeriveInput) -> syn::Result<Self> {
+        let Data::Enum(data) = &input.data else {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "BizErrorCode can only be derived for enums",
+            ));
+        };
+        if !input.generics.params.is_empty() {
+            return Err(syn::Error::new(
+                input.generics.span(),
+                "BizErrorCode cannot be derived for generic enums",
+            ));
+        }
+
+        let mut config = BizErrorConfig {
+            default_language: "en".to_string(),
+            supported_languages: Vec::new(),
+            fallbacks: IndexMap::new(),
+            missing_translation: MissingTranslation::default(),
+            type_base_uri: None,
+            categories: IndexMap::new(),
+            errors: IndexMap::new(),
+            groups: IndexMap::new(),
+            fluent: IndexMap::new(),
+        };
+        parse_enum_attrs(input, &mut config)?;
+
+        // 错误名 -> 变体名
+        let mut variants: IndexMap<String, String> = IndexMap::new();
+        for variant in &data.variants {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    "BizErrorCode variants cannot have fields",
+                ));
+            }
+
+            let name = variant.ident.to_string();
+            let key = to_snake_case(&name);
+            if let Some(other) = variants.get(&key) {
+                return Err(syn::Error::new(
+                    variant.ident.span(),
+                    format!("variants `{}` and `{}` both map to error key `{}`", other, name, key),
+                ));
+            }
+
+            config.errors.insert(key.clone(), parse_variant_attrs(variant)?);
+            variants.insert(key, name);
+        }
+
+        let mut catalog = ErrorCatalog::from_config(config).map_err(|e| match e {
+            Error::Validation { problems, .. } => {
+                let mut message = format!("invalid error codes in `{}`", input.ident);
+                for problem in problems {
+                    message.push_str("\n  - ");
+                    message.push_str(&problem);
+                }
+                syn::Error::new(input.ident.span(), message)
+            }
+            e => syn::Error::new(input.ident.span(), e.to_string()),
+        })?;
+
+        for entry in &mut catalog.errors {
+            entry.variant = variants[&entry.key].clone();
+        }
+        Ok(catalog)
+    }
+}
+
+/// 解析枚举上的 `#[biz(...)]`：语言配置和 `type_base_uri`
+fn parse_enum_attrs(input: &DeriveInput, config: &mut BizErrorConfig) -> syn::Result<()> {
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("biz")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default_language") {
+                config.default_language = meta.value()?.parse::<LitStr>()?.value();
+            } else if meta.path.is_ident("supported_languages") {
+                let array: ExprArray = meta.value()?.parse()?;
+                config.supported_languages = array
+                    .elems
+                    .iter()
+                    .map(|elem| match elem {
+                        Expr::Lit(expr) => match &expr.lit {
+                            Lit::Str(lit) => Ok(lit.value()),
+                            lit => Err(syn::Error::new(lit.span(), "expected a string literal")),
+                        },
+                        elem => Err(syn::Error::new(elem.span(), "expected a string literal")),
+                    })
+                    .collect::<syn::Result<_>>()?;
+            } else if meta.path.is_ident("missing_translation") {
+                let lit: LitStr = meta.value()?.parse()?;
+                config.missing_translation = match lit.value().as_str() {
+                    "error" => MissingTranslation::Error,
+                    "warn" => MissingTranslation::Warn,
+                    "fallback" => MissingTranslation::Fallback,
+                    _ => {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "expected one of `error`, `warn`, `fallback`",
+                        ));
+                    }
+                };
+            } else if meta.path.is_ident("type_base_uri") {
+                config.type_base_uri = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else {
+                return Err(meta.error(
+                    "unknown attribute (expected `default_language`, `supported_languages`, \
+                     `missing_translation` or `type_base_uri`)",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+/// 解析变体上的 `#[biz(code = ..., http = ..., <语言> = "...")]`
+///
+/// 语言名中的 `_` 转换为 `-`（`zh_CN` → `zh-CN`）
+fn parse_variant_attrs(variant: &syn::Variant) -> syn::Result<ErrorDef> {
+    let mut code = None;
+    let mut http_status = None;
+    let mut message: IndexMap<String, String> = IndexMap::new();
+
+    for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident("biz")) {
+        attr.parse_nested_meta(|meta| {
+            let Some(ident) = meta.path.get_ident() else {
+                return Err(meta.error("expected `code`, `http` or a language such as `zh_CN`"));
+            };
+            match ident.to_string().as_str() {
+                "code" => {
+                    if code.is_some() {
+                        return Err(meta.error("duplicate `code`"));
+                    }
+                    code = Some(parse_code(&meta.value()?.parse::<Expr>()?)?);
+                }
+                "http" => {
+                    if http_status.is_some() {
+                        return Err(meta.error("duplicate `http`"));
+                    }
+                    http_status = Some(meta.value()?.parse::<LitInt>()?.base10_parse::<u16>()?);
+                }
+                lang => {
+                    let lang = lang.replace('_', "-");
+                    let msg = meta.value()?.parse::<LitStr>()?.value();
+                    if message.insert(lang.clone(), msg).is_some() {
+                        return Err(meta.error(format!("duplicate `{}` message", lang)));
+                    }
+                }
+            }
+            Ok(())
+        })?;
+    }
+
+    let Some(code) = code else {
+        return Err(syn::Error::new(
+            variant.ident.span(),
+            format!("missing `#[biz(code = ...)]` on variant `{}`", variant.ident),
+        ));
+    };
+
+    Ok(ErrorDef {
+        code,
+        http_status,
+        category: None,
+        description: None,
+        args: IndexMap::new(),
+        message,
+    })
+}
+
+/// 错误码：整数字面量，可以带负号（与 YAML 中的 `code` 一样接受任意 i32）
+fn parse_code(expr: &Expr) -> syn::Result<i32> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_parse::<i32>(),
+        Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr: inner, .. }) => match &**inner {
+            Expr::Lit(ExprLit { lit: Lit::Int(lit), .. }) => {
+                let value = -lit.base10_parse::<i64>()?;
+                i32::try_from(value)
+                    .map_err(|_| syn::Error::new_spanned(expr, "code does not fit in i32"))
+            }
+            _ => Err(syn::Error::new_spanned(expr, "expected an integer code")),
+        },
+        _ => Err(syn::Error::new_spanned(expr, "expected an integer code")),
+    }
+}
+
+/// PascalCase 转换为 snake_case（`InvalidParam` → `invalid_param`，`HTTPError` → `http_error`）
+fn to_snake_case(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::with_capacity(s.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_is_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
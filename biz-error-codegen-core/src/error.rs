@@ -0,0 +1,159 @@
+// 📦 错误类型 - 读取、解析配置和生成代码过程中的错误
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// 代码生成过程中的错误
+///
+/// `Display` 输出形如 `biz_errors.yaml:12:5: errors.invalid_param: unknown field ...`，
+/// 过程宏会将其原样放入 `compile_error!`。
+#[derive(Debug)]
+pub enum Error {
+    /// 读写文件失败
+    Io {
+        /// 文件路径
+        path: PathBuf,
+        /// 底层 IO 错误
+        source: std::io::Error,
+    },
+    /// 配置文件格式错误（未知字段、类型错误、缺少字段等）
+    Parse {
+        /// 配置文件路径（从字符串解析时为 `None`）
+        path: Option<PathBuf>,
+        /// 出错的行号（从 1 开始）
+        line: Option<usize>,
+        /// 出错的列号（从 1 开始）
+        column: Option<usize>,
+        /// 错误描述（不含位置信息）
+        message: String,
+    },
+    /// 配置内容不合法（重复错误码、重复变体名、非法 HTTP 状态码等）
+    Validation {
+        /// 配置文件路径（从字符串解析时为 `None`）
+        path: Option<PathBuf>,
+        /// 所有问题的描述
+        problems: Vec<String>,
+    },
+    /// 生成的代码无法被解析（生成器自身的 bug）
+    Render(syn::Error),
+}
+
+impl Error {
+    /// 为尚未关联文件的错误补充配置文件路径
+    pub(crate) fn with_path(mut self, file: &Path) -> Self {
+        match &mut self {
+            Error::Parse { path, .. } | Error::Validation { path, .. } if path.is_none() => {
+                *path = Some(file.to_path_buf());
+            }
+            _ => {}
+        }
+        self
+    }
+
+    /// 从 serde_yaml 错误构造，提取行列号
+    pub(crate) fn from_yaml(path: Option<PathBuf>, err: serde_yaml::Error) -> Self {
+        let location = err.location();
+        let line = location.as_ref().map(|l| l.line());
+        let column = location.as_ref().map(|l| l.column());
+
+        Self::Parse {
+            path,
+            line,
+            column,
+            message: strip_location(err.to_string(), line, column),
+        }
+    }
+
+    /// 从 serde_json 错误构造，提取行列号
+    #[cfg(feature = "json")]
+    pub(crate) fn from_json(err: serde_json::Error) -> Self {
+        // serde_json 在无法定位时行号为 0
+        let line = (err.line() > 0).then_some(err.line());
+        let column = line.map(|_| err.column());
+        Self::Parse {
+            path: None,
+            line,
+            column,
+            message: strip_location(err.to_string(), line, column),
+        }
+    }
+
+    /// 从 toml 错误构造，根据出错的字节位置计算行列号
+    #[cfg(feature = "toml")]
+    pub(crate) fn from_toml(content: &str, err: toml::de::Error) -> Self {
+        let (line, column) = match err.span() {
+            Some(span) => {
+                let before = &content[..span.start];
+                let line = before.matches('\n').count() + 1;
+                let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+                (Some(line), Some(column))
+            }
+            None => (None, None),
+        };
+        Self::Parse {
+            path: None,
+            line,
+            column,
+            message: err.message().to_string(),
+        }
+    }
+}
+
+/// serde_yaml / serde_json 的 Display 末尾自带 " at line X column Y"，位置信息单独保存
+fn strip_location(message: String, line: Option<usize>, column: Option<usize>) -> String {
+    if let (Some(line), Some(column)) = (line, column) {
+        let suffix = format!(" at line {} column {}", line, column);
+        if let Some(stripped) = message.strip_suffix(&suffix) {
+            return stripped.to_string();
+        }
+    }
+    message
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Error::Parse {
+                path,
+                line,
+                column,
+                message,
+            } => {
+                match path {
+                    Some(path) => write!(f, "{}", path.display())?,
+                    None => write!(f, "<string>")?,
+                }
+                if let Some(line) = line {
+                    write!(f, ":{}", line)?;
+                }
+                if let Some(column) = column {
+                    write!(f, ":{}", column)?;
+                }
+                write!(f, ": {}", message)
+            }
+            Error::Validation { path, problems } => {
+                match path {
+                    Some(path) => write!(f, "{}: ", path.display())?,
+                    None => write!(f, "<string>: ")?,
+                }
+                write!(f, "invalid error catalog")?;
+                for problem in problems {
+                    write!(f, "\n  - {}", problem)?;
+                }
+                Ok(())
+            }
+            Error::Render(err) => write!(f, "generated code is not valid Rust: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse { .. } | Error::Validation { .. } => None,
+            Error::Render(err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+// 📦 过程宏展开 - `#[generate_error_codes]` 和 `#[derive(BizErrorCode)]` 的完整展开逻辑
+//
+// 过程宏 crate 只能导出宏本身，展开逻辑放在这里，宏只负责 `proc_macro` 与 `proc_macro2` 之间的转换；
+// 测试因此可以直接检查宏实际输出的代码（例如与 build.rs 生成的 golden 文件比较）。
+
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{AttrStyle, DeriveInput, ItemMod, LitStr};
+
+use crate::generator::{generate, generate_derive};
+use crate::model::ErrorCatalog;
+
+/// 展开 `#[generate_error_codes("path")]`：`args` 为属性参数，`input` 为标注的模块
+///
+/// 相对路径以 `CARGO_MANIFEST_DIR` 为基准。生成的代码放入原模块，
+/// 之后是跟踪配置文件的 `include_bytes!` 常量和校验警告，最后是模块中手写的内容。
+pub fn expand_module(args: TokenStream, input: TokenStream) -> TokenStream {
+    // 解析属性参数（YAML 文件路径）
+    let yaml_lit = match syn::parse2::<LitStr>(args) {
+        Ok(path) => path,
+        Err(e) => {
+            let error = format!("Invalid YAML file path: {}", e);
+            return quote!(compile_error!(#error));
+        }
+    };
+    let yaml_path = resolve_path(&yaml_lit.value());
+
+    // 解析输入的模块声明以获取模块名称
+    let module = match syn::parse2::<ItemMod>(input) {
+        Ok(mod_item) => mod_item,
+        Err(e) => {
+            let error = format!("Invalid module declaration: {}", e);
+            return quote!(compile_error!(#error));
+        }
+    };
+
+    // 在编译时生成代码（与 build.rs 方式共用同一个生成器）
+    let expanded = match ErrorCatalog::from_path(&yaml_path) {
+        Ok(catalog) => {
+            let code = generate(&catalog);
+            let tracked = track_files(&catalog.files);
+            let warnings = emit_warnings(yaml_lit.span(), &catalog.warnings);
+            quote!(#code #tracked #warnings)
+        }
+        Err(e) => {
+            // 错误信息包含配置文件的行列号，指向属性中的路径字面量
+            let error_msg = format!("Failed to generate error codes: {}", e);
+            return syn::Error::new(yaml_lit.span(), error_msg).to_compile_error();
+        }
+    };
+
+    // 将生成的代码放入原模块：保留可见性、属性和模块中已有的内容
+    let ItemMod {
+        attrs,
+        vis,
+        ident,
+        content,
+        ..
+    } = module;
+    let (inner_attrs, outer_attrs): (Vec<_>, Vec<_>) = attrs
+        .into_iter()
+        .partition(|attr| matches!(attr.style, AttrStyle::Inner(_)));
+    let items = content.map(|(_, items)| items).unwrap_or_default();
+
+    quote! {
+        #(#outer_attrs)*
+        #vis mod #ident {
+            #(#inner_attrs)*
+
+            #expanded
+
+            #(#items)*
+        }
+    }
+}
+
+/// 展开 `#[derive(BizErrorCode)]`
+pub fn expand_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse2::<DeriveInput>(input) {
+        Ok(input) => input,
+        Err(e) => return e.to_compile_error(),
+    };
+
+    match ErrorCatalog::from_derive_input(&input) {
+        Ok(catalog) => {
+            let code = generate_derive(&catalog, &input.ident);
+            let warnings = emit_warnings(input.ident.span(), &catalog.warnings);
+            quote!(#code #warnings)
+        }
+        Err(e) => e.to_compile_error(),
+    }
+}
+
+/// 相对路径以 `CARGO_MANIFEST_DIR` 为基准，不依赖编译器的工作目录
+/// （在 workspace 中编译成员 crate 时，工作目录是 workspace 根目录）
+fn resolve_path(path: &str) -> PathBuf {
+    match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(dir) => Path::new(&dir).join(path),
+        None => PathBuf::from(path),
+    }
+}
+
+/// 引用读取过的每个配置文件，文件修改后 cargo 会重新展开宏
+///
+/// 稳定版过程宏无法直接声明依赖的文件，`include_bytes!` 会把文件记录到 crate 的依赖信息中；
+/// 匿名常量未被使用，不会进入编译产物。
+/// 只能跟踪文件：目录或 glob 中之后新增的文件无法被跟踪（见 `generate_error_codes` 的文档）
+fn track_files(files: &[PathBuf]) -> TokenStream {
+    let paths = files.iter().map(|path| path.to_string_lossy().into_owned());
+    quote! {
+        #(const _: &[u8] = include_bytes!(#paths);)*
+    }
+}
+
+/// 将校验警告（如 `missing_translation: warn` 下缺失的翻译）转换为编译警告
+///
+/// 稳定版过程宏无法直接发出警告，这里引用一个带说明的 `#[deprecated]` 常量，
+/// 由编译器在路径字面量处报告该说明
+///
+/// 每条警告放在单独的匿名常量中，不会与用户模块中的名字冲突
+fn emit_warnings(span: Span, warnings: &[String]) -> TokenStream {
+    let name = format_ident!("BIZ_ERROR_WARNING", span = span);
+    let items = warnings.iter().map(|warning| {
+        quote_spanned! {span=>
+            const _: () = {
+                #[deprecated(note = #warning)]
+                const #name: () = ();
+                #name
+            };
+        }
+    });
+    quote!(#(#items)*)
+}
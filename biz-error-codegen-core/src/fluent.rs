@@ -0,0 +1,290 @@
+// 📦 Fluent 资源 - 读取 `.ftl`，为每条消息生成扁平模板并收集引用的变量
+//
+// Fluent 用于表达普通 `{name}` 模板无法表达的翻译（复数、性别等）：
+//
+// ```ftl
+// files_remaining = { $count ->
+//     [one] Остался { $count } файл
+//     [few] Осталось { $count } файла
+//    *[many] Осталось { $count } файлов
+// }
+// ```
+//
+// - 消息 ID 为错误名，校验时检查每条消息都对应一个错误，引用的变量都是该错误的参数
+// - `.ftl` 内容原样嵌入生成的代码（`ErrorCode::fluent_resource`），由 `biz_error` 的 `fluent` feature 在运行时渲染
+// - 每条消息同时扁平化为普通模板（选择表达式取默认变体，`{ $count }` → `{count}`），
+//   作为 `message_lang` 的返回值，未启用运行时渲染时也能得到可读的消息
+
+use std::path::PathBuf;
+
+use crate::Error;
+use crate::model::FluentEntry;
+use crate::schema::FluentSource;
+
+/// 读取并解析一种语言的 Fluent 资源
+pub(crate) fn load(lang: String, source: FluentSource) -> Result<FluentEntry, Error> {
+    let (source, file) = match source {
+        FluentSource::File(path) => {
+            let path = PathBuf::from(path);
+            let source = std::fs::read_to_string(&path).map_err(|source| Error::Io {
+                path: path.clone(),
+                source,
+            })?;
+            (source, Some(path))
+        }
+        FluentSource::Inline { source } => (source, None),
+    };
+
+    let messages = parse::messages(&source).map_err(|e| match file {
+        Some(ref file) => e.with_path(file),
+        None => e,
+    })?;
+    Ok(FluentEntry {
+        lang,
+        source,
+        file,
+        messages,
+    })
+}
+
+#[cfg(not(feature = "fluent"))]
+mod parse {
+    use crate::Error;
+    use crate::model::FluentMessage;
+
+    pub(super) fn messages(_source: &str) -> Result<Vec<FluentMessage>, Error> {
+        Err(Error::Parse {
+            path: None,
+            line: None,
+            column: None,
+            message: "Fluent resources require the `fluent` feature of biz-error-codegen-core \
+                      (`fluent` feature of biz-error)"
+                .to_string(),
+        })
+    }
+}
+
+#[cfg(feature = "fluent")]
+mod parse {
+    use std::collections::HashMap;
+
+    use fluent_syntax::ast::{Entry, Expression, InlineExpression, Pattern, PatternElement};
+
+    use crate::Error;
+    use crate::model::FluentMessage;
+
+    /// 消息、term 互相引用时的最大展开深度（防止循环引用）
+    const MAX_DEPTH: usize = 8;
+
+    /// 解析 `.ftl` 内容，返回其中的消息
+    pub(super) fn messages(source: &str) -> Result<Vec<FluentMessage>, Error> {
+        let resource = fluent_syntax::parser::parse(source).map_err(|(_, errors)| {
+            let error = &errors[0];
+            let before = &source[..error.pos.start.min(source.len())];
+            let line = before.matches('\n').count() + 1;
+            let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+            Error::Parse {
+                path: None,
+                line: Some(line),
+                column: Some(column),
+                message: error.kind.to_string(),
+            }
+        })?;
+
+        let mut resolver = Resolver::default();
+        for entry in &resource.body {
+            match entry {
+                Entry::Message(message) => {
+                    if let Some(ref value) = message.value {
+                        resolver.messages.insert(message.id.name, value);
+                    }
+                    for attr in &message.attributes {
+                        resolver
+                            .attributes
+                            .insert((message.id.name, attr.id.name), &attr.value);
+                    }
+                }
+                Entry::Term(term) => {
+                    resolver.terms.insert(term.id.name, &term.value);
+                    for attr in &term.attributes {
+                        resolver
+                            .term_attributes
+                            .insert((term.id.name, attr.id.name), &attr.value);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(resource
+            .body
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::Message(message) => Some(message),
+                _ => None,
+            })
+            .map(|message| {
+                let mut variables = Vec::new();
+                let template = message.value.as_ref().map(|value| {
+                    resolver.variables(value, &mut variables, 0);
+                    let mut out = String::new();
+                    resolver.flatten(value, &mut out, 0);
+                    out
+                });
+                FluentMessage {
+                    id: message.id.name.to_string(),
+                    template,
+                    variables,
+                }
+            })
+            .collect())
+    }
+
+    /// 按名称查找资源中的消息和 term，用于展开引用
+    #[derive(Default)]
+    struct Resolver<'a> {
+        messages: HashMap<&'a str, &'a Pattern<&'a str>>,
+        attributes: HashMap<(&'a str, &'a str), &'a Pattern<&'a str>>,
+        terms: HashMap<&'a str, &'a Pattern<&'a str>>,
+        term_attributes: HashMap<(&'a str, &'a str), &'a Pattern<&'a str>>,
+    }
+
+    impl<'a> Resolver<'a> {
+        /// 被引用的消息或 term
+        fn reference(&self, inline: &InlineExpression<&'a str>) -> Option<&'a Pattern<&'a str>> {
+            match inline {
+                InlineExpression::MessageReference { id, attribute } => match attribute {
+                    Some(attr) => self.attributes.get(&(id.name, attr.name)).copied(),
+                    None => self.messages.get(id.name).copied(),
+                },
+                InlineExpression::TermReference { id, attribute, .. } => match attribute {
+                    Some(attr) => self.term_attributes.get(&(id.name, attr.name)).copied(),
+                    None => self.terms.get(id.name).copied(),
+                },
+                _ => None,
+            }
+        }
+
+        /// 扁平化为 `{name}` 模板
+        fn flatten(&self, pattern: &Pattern<&'a str>, out: &mut String, depth: usize) {
+            for element in &pattern.elements {
+                match element {
+                    PatternElement::TextElement { value } => push_escaped(out, value),
+                    PatternElement::Placeable { expression } => {
+                        self.flatten_expression(expression, out, depth)
+                    }
+                }
+            }
+        }
+
+        fn flatten_expression(&self, expression: &Expression<&'a str>, out: &mut String, depth: usize) {
+            match expression {
+                Expression::Select { variants, .. } => {
+                    if let Some(variant) = variants.iter().find(|v| v.default) {
+                        self.flatten(&variant.value, out, depth);
+                    }
+                }
+                Expression::Inline(inline) => self.flatten_inline(inline, out, depth),
+            }
+        }
+
+        fn flatten_inline(&self, inline: &InlineExpression<&'a str>, out: &mut String, depth: usize) {
+            match inline {
+                InlineExpression::StringLiteral { value } => push_escaped(out, value),
+                InlineExpression::NumberLiteral { value } => out.push_str(value),
+                InlineExpression::VariableReference { id } => {
+                    out.push('{');
+                    out.push_str(id.name);
+                    out.push('}');
+                }
+                // 函数（如 `NUMBER($count)`）取第一个位置参数
+                InlineExpression::FunctionReference { arguments, .. } => {
+                    if let Some(first) = arguments.positional.first() {
+                        self.flatten_inline(first, out, depth);
+                    }
+                }
+                InlineExpression::MessageReference { .. } | InlineExpression::TermReference { .. } => {
+                    if depth < MAX_DEPTH
+                        && let Some(pattern) = self.reference(inline)
+                    {
+                        self.flatten(pattern, out, depth + 1);
+                    }
+                }
+                InlineExpression::Placeable { expression } => {
+                    self.flatten_expression(expression, out, depth)
+                }
+            }
+        }
+
+        /// 收集引用的变量（包括所有变体和被引用的消息；term 有独立的参数作用域，不收集）
+        fn variables(&self, pattern: &Pattern<&'a str>, names: &mut Vec<String>, depth: usize) {
+            for element in &pattern.elements {
+                if let PatternElement::Placeable { expression } = element {
+                    self.expression_variables(expression, names, depth);
+                }
+            }
+        }
+
+        fn expression_variables(
+            &self,
+            expression: &Expression<&'a str>,
+            names: &mut Vec<String>,
+            depth: usize,
+        ) {
+            match expression {
+                Expression::Select { selector, variants } => {
+                    self.inline_variables(selector, names, depth);
+                    for variant in variants {
+                        self.variables(&variant.value, names, depth);
+                    }
+                }
+                Expression::Inline(inline) => self.inline_variables(inline, names, depth),
+            }
+        }
+
+        fn inline_variables(
+            &self,
+            inline: &InlineExpression<&'a str>,
+            names: &mut Vec<String>,
+            depth: usize,
+        ) {
+            match inline {
+                InlineExpression::VariableReference { id }
+                    if !names.iter().any(|name| name == id.name) =>
+                {
+                    names.push(id.name.to_string());
+                }
+                InlineExpression::FunctionReference { arguments, .. } => {
+                    for argument in &arguments.positional {
+                        self.inline_variables(argument, names, depth);
+                    }
+                    for argument in &arguments.named {
+                        self.inline_variables(&argument.value, names, depth);
+                    }
+                }
+                InlineExpression::MessageReference { .. } => {
+                    if depth < MAX_DEPTH
+                        && let Some(pattern) = self.reference(inline)
+                    {
+                        self.variables(pattern, names, depth + 1);
+                    }
+                }
+                InlineExpression::Placeable { expression } => {
+                    self.expression_variables(expression, names, depth)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// 追加文本，`{` / `}` 转义为 `{{` / `}}`
+    fn push_escaped(out: &mut String, text: &str) {
+        for c in text.chars() {
+            match c {
+                '{' => out.push_str("{{"),
+                '}' => out.push_str("}}"),
+                c => out.push(c),
+            }
+        }
+    }
+}
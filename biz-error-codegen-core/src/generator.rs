@@ -0,0 +1,568 @@
+// 📦 代码生成器 - 从 ErrorCatalog 生成 ErrorCode 枚举
+//
+// `generate` 产出的 TokenStream 由过程宏直接展开，
+// `render` 将同一份 TokenStream 格式化为源码供 build.rs 写入文件
+//
+// 配置了 `groups` 时，每个分组生成一个独立的枚举（如 `UserError`），
+// `ErrorCode` 则是汇总所有分组的枚举（`ErrorCode::User(UserError)`）：
+//
+// ```text
+// UserError ──┐
+//             ├──From──> ErrorCode
+// OrderError ─┘
+// ```
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::Error;
+use crate::model::ErrorCatalog;
+
+/// build.rs 生成文件的头部注释
+const FILE_HEADER: &str = "\
+// 🔥 此文件由 biz-error 自动生成，请勿手动编辑！
+// 💡 如需修改错误码，请编辑 biz_errors.yaml 文件后重新编译
+
+";
+
+/// 从错误码目录生成 ErrorCode 枚举及其 trait 实现
+///
+/// 配置了分组时生成各分组的枚举和汇总枚举 `ErrorCode`
+pub fn generate(catalog: &ErrorCatalog) -> TokenStream {
+    if !catalog.groups.is_empty() {
+        return generate_grouped(catalog);
+    }
+
+    let ty = Ident::new("ErrorCode", Span::call_site());
+    let enum_variants = generate_enum_variants(catalog);
+    let variant_names = variant_idents(catalog);
+
+    let impls = generate_impls(catalog, &ty);
+    let constructors = generate_constructors(catalog, &ty);
+    let categories = generate_categories(catalog);
+
+    quote! {
+        use ::biz_error::ErrorCode as ErrorCodeTrait;
+
+        /// 自动生成的业务错误码枚举
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum ErrorCode {
+            #(#enum_variants)*
+        }
+
+        #impls
+
+        /// 所有错误码常量列表（用于反射或遍历）
+        pub const ALL_ERROR_CODES: &[ErrorCode] = &[#(ErrorCode::#variant_names),*];
+
+        #constructors
+
+        #categories
+    }
+}
+
+/// 为 `#[derive(BizErrorCode)]` 标注的枚举生成 trait 实现
+///
+/// 与 YAML 方式生成相同的 `ErrorCode` trait 实现、`Display` 和构造函数；
+/// `ALL_ERROR_CODES` 生成为关联常量，避免同一模块中的多个枚举冲突。
+/// 所有实现包在匿名常量中，不会向用户的模块引入任何名字。
+pub fn generate_derive(catalog: &ErrorCatalog, ty: &Ident) -> TokenStream {
+    let impls = generate_impls(catalog, ty);
+    let constructors = generate_constructors(catalog, ty);
+    let variant_names = variant_idents(catalog);
+
+    quote! {
+        const _: () = {
+            use ::biz_error::ErrorCode as ErrorCodeTrait;
+
+            #impls
+
+            impl #ty {
+                /// 所有错误码常量列表（用于反射或遍历）
+                pub const ALL_ERROR_CODES: &'static [#ty] = &[#(#ty::#variant_names),*];
+            }
+
+            #constructors
+        };
+    }
+}
+
+/// 生成各分组的枚举，以及汇总所有分组的 `ErrorCode` 枚举和 `From` 转换
+fn generate_grouped(catalog: &ErrorCatalog) -> TokenStream {
+    let mut group_enums = Vec::new();
+    let mut umbrella_variants = Vec::new();
+    let mut conversions = Vec::new();
+    let mut all_error_codes = Vec::new();
+    let mut group_variants = Vec::new();
+
+    for group in &catalog.groups {
+        let sub = catalog.group_catalog(group);
+        let ty = Ident::new(&group.name, Span::call_site());
+        let variant = Ident::new(&group.variant, Span::call_site());
+        let doc = group.description.as_deref().unwrap_or(&group.key);
+
+        let enum_variants = generate_enum_variants(&sub);
+        let variant_names = variant_idents(&sub);
+        let impls = generate_impls(&sub, &ty);
+        let constructors = generate_constructors(&sub, &ty);
+        let category_fn = generate_category_fn(&sub, &ty);
+
+        group_enums.push(quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum #ty {
+                #(#enum_variants)*
+            }
+
+            #impls
+
+            impl #ty {
+                /// 该分组的所有错误码常量列表
+                pub const ALL_ERROR_CODES: &'static [#ty] = &[#(#ty::#variant_names),*];
+            }
+
+            #constructors
+
+            #category_fn
+        });
+
+        umbrella_variants.push(quote! {
+            #[doc = #doc]
+            #variant(#ty),
+        });
+        conversions.push(quote! {
+            impl ::std::convert::From<#ty> for ErrorCode {
+                fn from(code: #ty) -> Self {
+                    ErrorCode::#variant(code)
+                }
+            }
+        });
+        all_error_codes.extend(variant_idents(&sub).map(|name| quote!(ErrorCode::#variant(#ty::#name))));
+        group_variants.push(variant);
+    }
+
+    // 汇总枚举的 trait 方法委托给各分组的枚举
+    let delegate = |call: TokenStream| {
+        let arms = group_variants.iter().map(|variant| {
+            quote!(ErrorCode::#variant(code) => code.#call,)
+        });
+        quote! {
+            match self {
+                #(#arms)*
+            }
+        }
+    };
+    let code = delegate(quote!(code()));
+    let key = delegate(quote!(key()));
+    let message = delegate(quote!(message()));
+    let message_lang = delegate(quote!(message_lang(lang)));
+    let resolve_lang = delegate(quote!(resolve_lang(lang)));
+    let http_status_code = delegate(quote!(http_status_code()));
+    let statics = generate_static_methods(catalog);
+
+    let category_fn = (!catalog.categories.is_empty()).then(|| {
+        let category = delegate(quote!(category()));
+        quote! {
+            impl ErrorCode {
+                /// 获取错误码所属分类
+                pub fn category(&self) -> Category {
+                    #category
+                }
+            }
+        }
+    });
+    let category_enum = generate_category_enum(catalog);
+
+    quote! {
+        use ::biz_error::ErrorCode as ErrorCodeTrait;
+
+        #(#group_enums)*
+
+        /// 自动生成的业务错误码枚举（汇总所有分组）
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum ErrorCode {
+            #(#umbrella_variants)*
+        }
+
+        #(#conversions)*
+
+        impl ErrorCodeTrait for ErrorCode {
+            fn code(&self) -> i32 {
+                #code
+            }
+
+            fn key(&self) -> &'static str {
+                #key
+            }
+
+            fn message(&self) -> &'static str {
+                #message
+            }
+
+            fn message_lang(&self, lang: &str) -> &'static str {
+                #message_lang
+            }
+
+            fn resolve_lang(&self, lang: &str) -> &'static str {
+                #resolve_lang
+            }
+
+            #statics
+
+            fn http_status_code(&self) -> u16 {
+                #http_status_code
+            }
+        }
+
+        impl ::std::fmt::Display for ErrorCode {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "[{}] {}", self.code(), self.message())
+            }
+        }
+
+        impl ::std::error::Error for ErrorCode {}
+
+        /// 所有错误码常量列表（用于反射或遍历）
+        pub const ALL_ERROR_CODES: &[ErrorCode] = &[#(#all_error_codes),*];
+
+        #category_enum
+
+        #category_fn
+    }
+}
+
+/// 生成枚举变体（文档注释使用默认语言的消息）
+fn generate_enum_variants(catalog: &ErrorCatalog) -> Vec<TokenStream> {
+    let default_lang = catalog.default_language.as_str();
+    catalog
+        .errors
+        .iter()
+        .map(|def| {
+            let variant = Ident::new(&def.variant, Span::call_site());
+            let default_msg = def.message(default_lang).unwrap_or("");
+            quote! {
+                #[doc = #default_msg]
+                #variant,
+            }
+        })
+        .collect()
+}
+
+/// 所有错误的枚举变体名
+fn variant_idents(catalog: &ErrorCatalog) -> impl Iterator<Item = Ident> + '_ {
+    catalog
+        .errors
+        .iter()
+        .map(|def| Ident::new(&def.variant, Span::call_site()))
+}
+
+/// 生成 `ErrorCode` trait 实现、`Display` 和 `Error`
+///
+/// 生成的代码通过 `ErrorCodeTrait` 引用 trait，由调用方负责引入。
+fn generate_impls(catalog: &ErrorCatalog, ty: &Ident) -> TokenStream {
+    let default_lang = catalog.default_language.as_str();
+
+    let mut code_match_arms = Vec::new();
+    let mut key_match_arms = Vec::new();
+    let mut message_match_arms = Vec::new();
+    let mut available_match_arms = Vec::new();
+    let mut http_status_match_arms = Vec::new();
+
+    for def in &catalog.errors {
+        let variant = Ident::new(&def.variant, Span::call_site());
+        let code = def.code;
+        let http_status = def.http_status;
+        let default_msg = def.message(default_lang).unwrap_or("");
+
+        // 生成 code() 方法分支
+        code_match_arms.push(quote! {
+            #ty::#variant => #code,
+        });
+
+        // 生成 key() 方法分支
+        let key = def.key.as_str();
+        key_match_arms.push(quote! {
+            #ty::#variant => #key,
+        });
+
+        // 生成 message_lang() 方法分支（每种语言 + 默认语言 fallback）
+        for (lang, msg) in &def.messages {
+            message_match_arms.push(quote! {
+                (#ty::#variant, #lang) => #msg,
+            });
+        }
+        message_match_arms.push(quote! {
+            (#ty::#variant, _) => #default_msg,
+        });
+
+        // 生成 resolve_lang() 中该错误实际拥有的语言
+        let available = def.messages.iter().map(|(lang, _)| lang);
+        available_match_arms.push(quote! {
+            #ty::#variant => &[#(#available),*],
+        });
+
+        // 生成 http_status_code() 方法分支（只使用数字状态码，不依赖任何 Web 框架）
+        http_status_match_arms.push(quote! {
+            #ty::#variant => #http_status,
+        });
+    }
+
+    let statics = generate_static_methods(catalog);
+    quote! {
+        impl ErrorCodeTrait for #ty {
+            fn code(&self) -> i32 {
+                match self {
+                    #(#code_match_arms)*
+                }
+            }
+
+            fn key(&self) -> &'static str {
+                match self {
+                    #(#key_match_arms)*
+                }
+            }
+
+            fn message(&self) -> &'static str {
+                self.message_lang(#default_lang)
+            }
+
+            fn message_lang(&self, lang: &str) -> &'static str {
+                match (self, self.resolve_lang(lang)) {
+                    #(#message_match_arms)*
+                }
+            }
+
+            fn resolve_lang(&self, lang: &str) -> &'static str {
+                let available: &[&'static str] = match self {
+                    #(#available_match_arms)*
+                };
+                ::biz_error::lang::resolve(lang, available, Self::fallbacks())
+                    .unwrap_or(#default_lang)
+            }
+
+            #statics
+
+            fn http_status_code(&self) -> u16 {
+                match self {
+                    #(#http_status_match_arms)*
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for #ty {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "[{}] {}", self.code(), self.message())
+            }
+        }
+
+        impl ::std::error::Error for #ty {}
+    }
+}
+
+/// 生成与具体错误无关的 trait 方法：支持的语言、默认语言、回退链和 `type_base_uri`
+fn generate_static_methods(catalog: &ErrorCatalog) -> TokenStream {
+    let default_lang = catalog.default_language.as_str();
+    let languages = catalog.languages();
+    let fallbacks = catalog.fallbacks.iter().map(|(requested, chain)| {
+        quote!((#requested, &[#(#chain),*]))
+    });
+    // 未配置时使用 trait 的默认实现（None）
+    let type_base_uri = catalog.type_base_uri.as_deref().map(|uri| {
+        quote! {
+            fn type_base_uri() -> ::std::option::Option<&'static str> {
+                ::std::option::Option::Some(#uri)
+            }
+        }
+    });
+
+    // 未配置 Fluent 资源时使用 trait 的默认实现（None）
+    let fluent_resource = (!catalog.fluent.is_empty()).then(|| {
+        let langs = catalog.fluent.iter().map(|r| r.lang.as_str());
+        let sources = catalog.fluent.iter().map(|r| r.source.as_str());
+        quote! {
+            fn fluent_resource(lang: &str) -> ::std::option::Option<&'static str> {
+                match lang {
+                    #(#langs => ::std::option::Option::Some(#sources),)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    });
+
+    quote! {
+        fn supported_languages() -> &'static [&'static str] {
+            &[#(#languages),*]
+        }
+
+        fn default_language() -> &'static str {
+            #default_lang
+        }
+
+        fn fallbacks() -> ::biz_error::lang::Fallbacks {
+            &[#(#fallbacks),*]
+        }
+
+        #type_base_uri
+
+        #fluent_resource
+    }
+}
+
+/// 为带参数的消息模板生成构造函数（没有带参数的错误时不生成）
+///
+/// 例如 `user_not_found: "User {user_id} not found"` 生成
+/// `ErrorCode::user_not_found(user_id) -> AppError<ErrorCode>`
+fn generate_constructors(catalog: &ErrorCatalog, ty: &Ident) -> TokenStream {
+    let default_lang = catalog.default_language.as_str();
+
+    let constructors: Vec<_> = catalog
+        .errors
+        .iter()
+        .filter(|entry| !entry.args.is_empty())
+        .map(|entry| {
+            let variant = Ident::new(&entry.variant, Span::call_site());
+            let method = Ident::new(&entry.key, Span::call_site());
+            let doc = format!(
+                " 构造 `{}` 错误：{}",
+                entry.variant,
+                entry.message(default_lang).unwrap_or("")
+            );
+
+            let names: Vec<_> = entry
+                .args
+                .iter()
+                .map(|arg| Ident::new(&arg.name, Span::call_site()))
+                .collect();
+            let keys = entry.args.iter().map(|arg| arg.name.as_str());
+            let params = entry.args.iter().zip(&names).map(|(arg, name)| match &arg.ty {
+                // 类型已在校验阶段确认可以解析
+                Some(ty) => {
+                    let ty: syn::Type = syn::parse_str(ty).expect("argument type is validated");
+                    quote!(#name: #ty)
+                }
+                None => quote!(#name: impl ::std::fmt::Display),
+            });
+
+            quote! {
+                #[doc = #doc]
+                pub fn #method(#(#params),*) -> ::biz_error::AppError<#ty> {
+                    ::biz_error::AppError::new(#ty::#variant)
+                        #(.with_arg(#keys, #names))*
+                }
+            }
+        })
+        .collect();
+
+    if constructors.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        impl #ty {
+            #(#constructors)*
+        }
+    }
+}
+
+/// 生成 `Category` 枚举及 `ErrorCode::category()`（未声明分类时不生成）
+fn generate_categories(catalog: &ErrorCatalog) -> TokenStream {
+    let category_enum = generate_category_enum(catalog);
+    let category_fn = generate_category_fn(catalog, &Ident::new("ErrorCode", Span::call_site()));
+    quote! {
+        #category_enum
+
+        #category_fn
+    }
+}
+
+/// 生成 `Category` 枚举（未声明分类时不生成）
+fn generate_category_enum(catalog: &ErrorCatalog) -> TokenStream {
+    if catalog.categories.is_empty() {
+        return TokenStream::new();
+    }
+
+    let mut category_variants = Vec::new();
+    let mut name_match_arms = Vec::new();
+    let mut range_match_arms = Vec::new();
+    let mut category_names = Vec::new();
+
+    for category in &catalog.categories {
+        let variant = Ident::new(&category.variant, Span::call_site());
+        let key = category.key.as_str();
+        let doc = category.description.as_deref().unwrap_or(key);
+        let (start, end) = (category.start, category.end);
+
+        category_variants.push(quote! {
+            #[doc = #doc]
+            #variant,
+        });
+        name_match_arms.push(quote! {
+            Category::#variant => #key,
+        });
+        range_match_arms.push(quote! {
+            Category::#variant => #start..=#end,
+        });
+        category_names.push(variant);
+    }
+
+    quote! {
+        /// 错误码分类
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Category {
+            #(#category_variants)*
+        }
+
+        impl Category {
+            /// 配置中的分类名
+            pub fn name(&self) -> &'static str {
+                match self {
+                    #(#name_match_arms)*
+                }
+            }
+
+            /// 分类的错误码范围
+            pub fn range(&self) -> ::std::ops::RangeInclusive<i32> {
+                match self {
+                    #(#range_match_arms)*
+                }
+            }
+        }
+
+        /// 所有分类常量列表
+        pub const ALL_CATEGORIES: &[Category] = &[#(Category::#category_names),*];
+    }
+}
+
+/// 生成 `category()` 方法（未声明分类时不生成）
+fn generate_category_fn(catalog: &ErrorCatalog, ty: &Ident) -> TokenStream {
+    if catalog.categories.is_empty() {
+        return TokenStream::new();
+    }
+
+    // 校验保证每个错误都归属于某个已声明的分类
+    let category_match_arms = catalog.errors.iter().filter_map(|entry| {
+        let category = catalog.category(entry.category.as_deref()?)?;
+        let variant = Ident::new(&entry.variant, Span::call_site());
+        let category = Ident::new(&category.variant, Span::call_site());
+        Some(quote! {
+            #ty::#variant => Category::#category,
+        })
+    });
+
+    quote! {
+        impl #ty {
+            /// 获取错误码所属分类
+            pub fn category(&self) -> Category {
+                match self {
+                    #(#category_match_arms)*
+                }
+            }
+        }
+    }
+}
+
+/// 生成格式化后的源码文件内容（供 build.rs 写入）
+pub fn render(catalog: &ErrorCatalog) -> Result<String, Error> {
+    let file: syn::File = syn::parse2(generate(catalog)).map_err(Error::Render)?;
+    Ok(format!("{}{}", FILE_HEADER, prettyplease::unparse(&file)))
+}
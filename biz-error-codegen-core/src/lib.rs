@@ -0,0 +1,44 @@
+// 📦 biz-error 代码生成核心
+//
+// 💡 build.rs 方式（`biz_error::codegen`）和过程宏方式（`#[generate_error_codes]`）
+//    共用同一份配置模型和代码生成器，保证两种方式生成的 ErrorCode 枚举完全一致；
+//    `#[derive(BizErrorCode)]` 从枚举属性构建同样的 ErrorCatalog，复用相同的校验和 trait 实现
+//
+// # 流程
+//
+// ```text
+// biz_errors.yaml ──> BizErrorConfig (schema) ──> ErrorCatalog (model) ──> TokenStream (generator)
+//                                                                     ├─> 过程宏：直接展开（expand）
+//                                                                     └─> build.rs：render() 格式化后写入文件
+// ```
+
+mod derive;
+pub mod error;
+pub mod expand;
+mod fluent;
+pub mod generator;
+mod loader;
+pub mod model;
+pub mod scaffold;
+pub mod schema;
+pub mod template;
+#[cfg(feature = "translations")]
+pub mod translations;
+mod validate;
+mod yaml_edit;
+
+pub use error::Error;
+pub use generator::{generate, generate_derive, render};
+pub use model::{
+    CategoryEntry, ErrorCatalog, ErrorEntry, FluentEntry, FluentMessage, GroupEntry, TemplateArg,
+};
+pub use scaffold::{NewError, add_error, next_code};
+pub use schema::{
+    BizErrorConfig, CategoryDef, ConfigFormat, ErrorDef, FluentSource, GroupDef,
+    MissingTranslation,
+};
+#[cfg(feature = "translations")]
+pub use translations::{
+    ImportReport, InvalidEntry, StaleEntry, TranslationUnit, Translations, export_po, export_xliff,
+    import_translations,
+};
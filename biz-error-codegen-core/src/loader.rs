@@ -0,0 +1,373 @@
+// 📦 配置加载 - 读取配置文件及其 include 的片段，合并为一份 BizErrorConfig
+//
+// 支持把错误码拆分到多个文件：
+//
+// ```yaml
+// # biz_errors.yaml
+// default_language: en
+// supported_languages: [en, zh-CN]
+// include:
+//   - errors/auth.yaml
+//   - errors/order/*.yaml
+// ```
+//
+// - `include` 中的路径相对于当前文件所在目录，可以是文件、目录或 glob 模式，片段中也可以继续 include
+// - `#[generate_error_codes]` / `from_path` 的参数本身也可以是目录或 glob 模式
+// - 每个文件的错误按「自身定义的错误 → include 的文件（按声明顺序）」合并
+// - `fluent` 中的 `.ftl` 路径同样相对于声明它的文件所在目录
+// - 同一个错误名、分类名、分组名、Fluent 语言在多个文件中定义，或同一项语言配置在多个文件中设置时报错，并指出涉及的文件
+// - 合并后的配置与单文件配置走同一套校验，重复错误码等问题的描述中会带上错误所在的文件
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use crate::Error;
+use crate::schema::{
+    self, BizErrorConfig, CategoryDef, ConfigFormat, ErrorDef, FluentSource, GroupDef,
+    MissingTranslation,
+};
+
+/// 单个配置文件（根配置或片段），语言配置均为可选，以便检测多个文件之间的冲突
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    #[serde(default)]
+    default_language: Option<String>,
+    #[serde(default)]
+    supported_languages: Option<Vec<String>>,
+    #[serde(default)]
+    fallbacks: Option<IndexMap<String, Vec<String>>>,
+    #[serde(default)]
+    missing_translation: Option<MissingTranslation>,
+    #[serde(default)]
+    type_base_uri: Option<String>,
+    /// 要合并的其他配置文件（相对于当前文件所在目录）
+    #[serde(default)]
+    include: Vec<String>,
+    #[serde(default)]
+    categories: IndexMap<String, CategoryDef>,
+    #[serde(default)]
+    errors: IndexMap<String, ErrorDef>,
+    #[serde(default)]
+    groups: IndexMap<String, GroupDef>,
+    #[serde(default)]
+    fluent: IndexMap<String, FluentSource>,
+}
+
+/// 加载结果
+pub(crate) struct Loaded {
+    /// 合并后的配置
+    pub config: BizErrorConfig,
+    /// (分组名, 错误名) -> 定义该错误的文件
+    pub error_files: HashMap<(Option<String>, String), PathBuf>,
+    /// 读取的所有文件（按读取顺序）
+    pub files: Vec<PathBuf>,
+}
+
+/// 读取配置文件（或目录、glob 模式）及其 include 的所有片段并合并
+pub(crate) fn load(path: &Path) -> Result<Loaded, Error> {
+    let mut merger = Merger::default();
+    for file in expand(path)? {
+        merger.load_file(&file, &mut Vec::new())?;
+    }
+    Ok(merger.finish())
+}
+
+/// 展开路径：glob 模式匹配的文件、目录下的配置文件（按文件名排序），或文件本身
+fn expand(path: &Path) -> Result<Vec<PathBuf>, Error> {
+    let no_match = || Error::Io {
+        path: path.to_path_buf(),
+        source: std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "no configuration files match this path",
+        ),
+    };
+
+    let pattern = path.to_string_lossy();
+    if pattern.contains(['*', '?', '[']) {
+        let paths = glob::glob(&pattern).map_err(|e| Error::Parse {
+            path: Some(path.to_path_buf()),
+            line: None,
+            column: None,
+            message: format!("invalid glob pattern: {}", e),
+        })?;
+        let mut files: Vec<_> = paths
+            .filter_map(Result::ok)
+            .filter(|p| p.is_file())
+            .collect();
+        files.sort();
+        return if files.is_empty() {
+            Err(no_match())
+        } else {
+            Ok(files)
+        };
+    }
+
+    if path.is_dir() {
+        let entries = std::fs::read_dir(path).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let mut files: Vec<_> = entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|p| p.is_file() && is_config_file(p))
+            .collect();
+        files.sort();
+        return if files.is_empty() {
+            Err(no_match())
+        } else {
+            Ok(files)
+        };
+    }
+
+    Ok(vec![path.to_path_buf()])
+}
+
+/// 目录中按扩展名识别的配置文件
+fn is_config_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext, "yaml" | "yml" | "json" | "toml"))
+}
+
+/// 逐个文件合并配置
+#[derive(Default)]
+struct Merger {
+    default_language: Option<(String, PathBuf)>,
+    supported_languages: Option<(Vec<String>, PathBuf)>,
+    fallbacks: Option<(IndexMap<String, Vec<String>>, PathBuf)>,
+    missing_translation: Option<(MissingTranslation, PathBuf)>,
+    type_base_uri: Option<(String, PathBuf)>,
+    categories: IndexMap<String, (CategoryDef, PathBuf)>,
+    errors: IndexMap<String, (ErrorDef, PathBuf)>,
+    groups: IndexMap<String, (GroupDef, PathBuf)>,
+    fluent: IndexMap<String, (FluentSource, PathBuf)>,
+    files: Vec<PathBuf>,
+    /// 已读取的文件（规范化路径），同一文件被多次 include 时只读取一次
+    seen: HashSet<PathBuf>,
+}
+
+impl Merger {
+    /// 读取并合并单个文件，`stack` 为当前的 include 链（用于检测循环 include）
+    fn load_file(&mut self, path: &Path, stack: &mut Vec<PathBuf>) -> Result<(), Error> {
+        let io_error = |source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        };
+        let canonical = std::fs::canonicalize(path).map_err(io_error)?;
+
+        if let Some(start) = stack.iter().position(|p| *p == canonical) {
+            let chain: Vec<_> = stack[start..]
+                .iter()
+                .chain([&canonical])
+                .map(|p| p.display().to_string())
+                .collect();
+            return Err(Error::Validation {
+                path: Some(path.to_path_buf()),
+                problems: vec![format!("include cycle: {}", chain.join(" -> "))],
+            });
+        }
+        if !self.seen.insert(canonical.clone()) {
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(path).map_err(io_error)?;
+        let file: ConfigFile = schema::parse_as(&content, ConfigFormat::from_path(path))
+            .map_err(|e| e.with_path(path))?;
+        self.files.push(path.to_path_buf());
+
+        let includes = self.merge(path, file)?;
+
+        stack.push(canonical);
+        let dir = path.parent().unwrap_or(Path::new(""));
+        for include in includes {
+            for child in expand(&dir.join(include))? {
+                self.load_file(&child, stack)?;
+            }
+        }
+        stack.pop();
+        Ok(())
+    }
+
+    /// 合并单个文件的内容，返回其 include 列表
+    fn merge(&mut self, path: &Path, file: ConfigFile) -> Result<Vec<String>, Error> {
+        let mut problems = Vec::new();
+
+        set_once(
+            &mut self.default_language,
+            file.default_language,
+            "default_language",
+            path,
+            &mut problems,
+        );
+        set_once(
+            &mut self.supported_languages,
+            file.supported_languages,
+            "supported_languages",
+            path,
+            &mut problems,
+        );
+        set_once(
+            &mut self.fallbacks,
+            file.fallbacks,
+            "fallbacks",
+            path,
+            &mut problems,
+        );
+        set_once(
+            &mut self.missing_translation,
+            file.missing_translation,
+            "missing_translation",
+            path,
+            &mut problems,
+        );
+        set_once(
+            &mut self.type_base_uri,
+            file.type_base_uri,
+            "type_base_uri",
+            path,
+            &mut problems,
+        );
+
+        for (key, def) in file.categories {
+            match self.categories.get(&key) {
+                Some((_, other)) => problems.push(format!(
+                    "category `{}` is already defined in {}",
+                    key,
+                    other.display()
+                )),
+                None => {
+                    self.categories.insert(key, (def, path.to_path_buf()));
+                }
+            }
+        }
+        for (key, def) in file.errors {
+            match self.errors.get(&key) {
+                Some((_, other)) => problems.push(format!(
+                    "error `{}` is already defined in {}",
+                    key,
+                    other.display()
+                )),
+                None => {
+                    self.errors.insert(key, (def, path.to_path_buf()));
+                }
+            }
+        }
+
+        for (key, def) in file.groups {
+            match self.groups.get(&key) {
+                Some((_, other)) => problems.push(format!(
+                    "group `{}` is already defined in {}",
+                    key,
+                    other.display()
+                )),
+                None => {
+                    self.groups.insert(key, (def, path.to_path_buf()));
+                }
+            }
+        }
+
+        // `.ftl` 路径相对于声明它的文件所在目录
+        let dir = path.parent().unwrap_or(Path::new(""));
+        for (lang, source) in file.fluent {
+            match self.fluent.get(&lang) {
+                Some((_, other)) => problems.push(format!(
+                    "Fluent resource for `{}` is already defined in {}",
+                    lang,
+                    other.display()
+                )),
+                None => {
+                    let source = match source {
+                        FluentSource::File(file) => {
+                            FluentSource::File(dir.join(file).to_string_lossy().into_owned())
+                        }
+                        inline => inline,
+                    };
+                    self.fluent.insert(lang, (source, path.to_path_buf()));
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(file.include)
+        } else {
+            Err(Error::Validation {
+                path: Some(path.to_path_buf()),
+                problems,
+            })
+        }
+    }
+
+    /// 生成合并后的配置，未设置的语言配置使用默认值
+    fn finish(self) -> Loaded {
+        let mut error_files = HashMap::new();
+        for (key, (_, file)) in &self.errors {
+            error_files.insert((None, key.clone()), file.clone());
+        }
+        for (group, (def, file)) in &self.groups {
+            for key in def.errors.keys() {
+                error_files.insert((Some(group.clone()), key.clone()), file.clone());
+            }
+        }
+
+        let config = BizErrorConfig {
+            default_language: self
+                .default_language
+                .map_or_else(schema::default_language, |(value, _)| value),
+            supported_languages: self.supported_languages.map(|(v, _)| v).unwrap_or_default(),
+            fallbacks: self.fallbacks.map(|(v, _)| v).unwrap_or_default(),
+            missing_translation: self.missing_translation.map(|(v, _)| v).unwrap_or_default(),
+            type_base_uri: self.type_base_uri.map(|(v, _)| v),
+            categories: self
+                .categories
+                .into_iter()
+                .map(|(k, (def, _))| (k, def))
+                .collect(),
+            errors: self
+                .errors
+                .into_iter()
+                .map(|(k, (def, _))| (k, def))
+                .collect(),
+            groups: self
+                .groups
+                .into_iter()
+                .map(|(k, (def, _))| (k, def))
+                .collect(),
+            fluent: self
+                .fluent
+                .into_iter()
+                .map(|(lang, (source, _))| (lang, source))
+                .collect(),
+        };
+
+        Loaded {
+            config,
+            error_files,
+            files: self.files,
+        }
+    }
+}
+
+/// 语言等全局配置只能在一个文件中设置
+fn set_once<T>(
+    slot: &mut Option<(T, PathBuf)>,
+    value: Option<T>,
+    name: &str,
+    path: &Path,
+    problems: &mut Vec<String>,
+) {
+    let Some(value) = value else {
+        return;
+    };
+    match slot {
+        Some((_, other)) => problems.push(format!(
+            "`{}` is already set in {} (global settings may only appear in one file)",
+            name,
+            other.display()
+        )),
+        None => *slot = Some((value, path.to_path_buf())),
+    }
+}
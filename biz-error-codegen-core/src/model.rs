@@ -0,0 +1,406 @@
+// 📦 配置模型 - 将配置文件转换为生成器使用的错误码目录
+
+use std::path::{Path, PathBuf};
+
+use crate::Error;
+use crate::schema::{BizErrorConfig, ConfigFormat, ErrorDef, MissingTranslation};
+use crate::{fluent, loader, template, validate};
+
+/// 未配置 http_status 且所属分类也未配置时使用的 HTTP 状态码
+pub const DEFAULT_HTTP_STATUS: u16 = 500;
+
+/// 错误码目录（对应整个 biz_errors.yaml）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorCatalog {
+    /// 默认语言
+    pub default_language: String,
+    /// 支持的语言列表
+    pub supported_languages: Vec<String>,
+    /// 语言回退链（请求的语言, 依次尝试的语言），保持配置文件中的顺序
+    pub fallbacks: Vec<(String, Vec<String>)>,
+    /// 缺少翻译时的处理策略
+    pub missing_translation: MissingTranslation,
+    /// Problem Details `type` URI 的前缀
+    pub type_base_uri: Option<String>,
+    /// 错误码分类（保持配置文件中的顺序）
+    pub categories: Vec<CategoryEntry>,
+    /// 错误码分组（保持配置文件中的顺序，未使用分组时为空）
+    pub groups: Vec<GroupEntry>,
+    /// 错误码定义（保持配置文件中的顺序，使用分组时按分组依次排列）
+    pub errors: Vec<ErrorEntry>,
+    /// 校验产生的警告（不影响代码生成，由调用方负责展示）
+    pub warnings: Vec<String>,
+    /// Fluent 资源（每种语言一个，保持配置文件中的顺序）
+    pub fluent: Vec<FluentEntry>,
+    /// 读取的所有文件（包括 include 的配置文件和 `.ftl` 文件，从字符串解析时只有 `.ftl` 文件）
+    pub files: Vec<PathBuf>,
+}
+
+/// 错误码分类
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryEntry {
+    /// 配置中的分类名（snake_case）
+    pub key: String,
+    /// 生成的 `Category` 枚举变体名（PascalCase）
+    pub variant: String,
+    /// 错误码范围起始（含）
+    pub start: i32,
+    /// 错误码范围结束（含）
+    pub end: i32,
+    /// 该分类下错误的默认 HTTP 状态码
+    pub http_status: Option<u16>,
+    /// 分类说明
+    pub description: Option<String>,
+}
+
+/// 错误码分组
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupEntry {
+    /// 配置中的分组名（snake_case）
+    pub key: String,
+    /// 汇总枚举 `ErrorCode` 中对应的变体名（PascalCase，如 `User`）
+    pub variant: String,
+    /// 生成的分组枚举名（如 `UserError`）
+    pub name: String,
+    /// 分组说明
+    pub description: Option<String>,
+}
+
+/// 单个错误码定义
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorEntry {
+    /// 配置中的错误名（snake_case）
+    pub key: String,
+    /// 生成的枚举变体名（PascalCase）
+    pub variant: String,
+    /// 数字错误码
+    pub code: i32,
+    /// HTTP 状态码
+    pub http_status: u16,
+    /// 所属分类名（显式声明或按范围推断，未声明分类时为 `None`）
+    pub category: Option<String>,
+    /// 所属分组名（未使用分组时为 `None`）
+    pub group: Option<String>,
+    /// 错误说明
+    pub description: Option<String>,
+    /// 消息模板参数（按生成的构造函数的参数顺序）
+    pub args: Vec<TemplateArg>,
+    /// 多语言消息（语言, 消息），保持配置文件中的顺序
+    pub messages: Vec<(String, String)>,
+    /// 定义该错误的配置文件（只在配置由多个文件合并而来时记录，用于诊断信息）
+    pub file: Option<PathBuf>,
+    /// 消息来自 Fluent 资源的语言（`messages` 中为扁平化后的模板）
+    pub fluent: Vec<String>,
+}
+
+/// 一种语言的 Fluent 资源
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FluentEntry {
+    /// 语言
+    pub lang: String,
+    /// `.ftl` 内容（原样嵌入生成的代码，由运行时渲染）
+    pub source: String,
+    /// `.ftl` 文件（内联时为 `None`）
+    pub file: Option<PathBuf>,
+    /// 资源中的消息（不含 term），保持文件中的顺序
+    pub messages: Vec<FluentMessage>,
+}
+
+/// Fluent 资源中的一条消息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FluentMessage {
+    /// 消息 ID（对应错误名）
+    pub id: String,
+    /// 消息值扁平化后的 `{name}` 模板（选择表达式取默认变体），消息没有值时为 `None`
+    pub template: Option<String>,
+    /// 消息（包括所有变体）引用的变量，按首次出现的顺序
+    pub variables: Vec<String>,
+}
+
+/// 消息模板参数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateArg {
+    /// 参数名（与占位符 `{name}` 对应）
+    pub name: String,
+    /// 声明的 Rust 类型（未声明时为 `None`，生成 `impl Display` 参数）
+    pub ty: Option<String>,
+}
+
+impl ErrorCatalog {
+    /// 从配置文件加载错误码目录（按扩展名选择 YAML / JSON / TOML 解析器，默认 YAML）
+    ///
+    /// `path` 也可以是目录（读取其中所有配置文件）或 glob 模式（如 `errors/*.yaml`），
+    /// 配置中的 `include:` 会一并读取，所有文件合并为一个错误码目录。
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        Self::from_path_unvalidated(path)?
+            .validated()
+            .map_err(|e| e.with_path(path))
+    }
+
+    /// 从配置文件加载错误码目录（不校验，用于修改配置文件的工具，如导入翻译）
+    pub(crate) fn from_path_unvalidated(path: &Path) -> Result<Self, Error> {
+        let loaded = loader::load(path)?;
+
+        let mut catalog = Self::build(loaded.config).map_err(|e| e.with_path(path))?;
+        if loaded.files.len() > 1 {
+            for entry in &mut catalog.errors {
+                let key = (entry.group.clone(), entry.key.clone());
+                entry.file = loaded.error_files.get(&key).cloned();
+            }
+        }
+        catalog.files.splice(0..0, loaded.files);
+        Ok(catalog)
+    }
+
+    /// 从 YAML 字符串解析错误码目录
+    pub fn from_yaml_str(content: &str) -> Result<Self, Error> {
+        Self::from_str_as(content, ConfigFormat::Yaml)
+    }
+
+    /// 按指定格式解析错误码目录
+    pub fn from_str_as(content: &str, format: ConfigFormat) -> Result<Self, Error> {
+        Self::from_config(BizErrorConfig::parse(content, format)?)
+    }
+
+    /// 从已解析的配置构建错误码目录并校验
+    pub fn from_config(config: BizErrorConfig) -> Result<Self, Error> {
+        Self::build(config)?.validated()
+    }
+
+    /// 从已解析的配置构建错误码目录（不校验，只读取和解析 Fluent 资源）
+    fn build(config: BizErrorConfig) -> Result<Self, Error> {
+        let fluent = config
+            .fluent
+            .into_iter()
+            .map(|(lang, source)| fluent::load(lang, source))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let categories: Vec<_> = config
+            .categories
+            .into_iter()
+            .map(|(key, def)| CategoryEntry {
+                variant: to_pascal_case(&key),
+                key,
+                start: def.range[0],
+                end: def.range[1],
+                http_status: def.http_status,
+                description: def.description,
+            })
+            .collect();
+
+        let default_lang = config.default_language.as_str();
+        let mut errors: Vec<_> = config
+            .errors
+            .into_iter()
+            .map(|(key, def)| ErrorEntry::build(key, def, None, &categories, default_lang))
+            .collect();
+
+        let mut groups = Vec::new();
+        for (group, def) in config.groups {
+            errors.extend(def.errors.into_iter().map(|(key, error)| {
+                ErrorEntry::build(key, error, Some(&group), &categories, default_lang)
+            }));
+            let variant = to_pascal_case(&group);
+            groups.push(GroupEntry {
+                name: format!("{}Error", variant),
+                variant,
+                key: group,
+                description: def.description,
+            });
+        }
+
+        let mut catalog = Self {
+            default_language: config.default_language,
+            supported_languages: config.supported_languages,
+            fallbacks: config.fallbacks.into_iter().collect(),
+            missing_translation: config.missing_translation,
+            type_base_uri: config.type_base_uri,
+            categories,
+            groups,
+            errors,
+            warnings: Vec::new(),
+            files: fluent.iter().filter_map(|r| r.file.clone()).collect(),
+            fluent,
+        };
+        catalog.apply_fluent();
+        Ok(catalog)
+    }
+
+    /// 将 Fluent 消息扁平化后的模板加入对应错误的消息
+    ///
+    /// 同一语言已有普通消息时保留普通消息（冲突由校验报告）；
+    /// 默认语言的消息来自 Fluent 且未声明 `args` 时，按 Fluent 消息引用的变量生成参数。
+    fn apply_fluent(&mut self) {
+        for resource in &self.fluent {
+            for message in &resource.messages {
+                let Some(ref template) = message.template else {
+                    continue;
+                };
+                for entry in self.errors.iter_mut().filter(|e| e.key == message.id) {
+                    if entry.message(&resource.lang).is_some() {
+                        continue;
+                    }
+                    entry.messages.push((resource.lang.clone(), template.clone()));
+                    entry.fluent.push(resource.lang.clone());
+
+                    let inferred = entry.args.iter().all(|arg| arg.ty.is_none());
+                    if inferred && resource.lang == self.default_language {
+                        entry.args = message
+                            .variables
+                            .iter()
+                            .map(|name| TemplateArg {
+                                name: name.clone(),
+                                ty: None,
+                            })
+                            .collect();
+                    }
+                }
+            }
+        }
+    }
+
+    /// 校验错误码目录，通过后记录警告
+    fn validated(mut self) -> Result<Self, Error> {
+        let findings = validate::validate(&self);
+        if !findings.problems.is_empty() {
+            return Err(Error::Validation {
+                path: None,
+                problems: findings.problems,
+            });
+        }
+        self.warnings = findings.warnings;
+
+        Ok(self)
+    }
+}
+
+impl ErrorCatalog {
+    /// 生成代码中的支持语言列表
+    ///
+    /// 声明了 supported_languages 时直接使用，否则为默认语言加上所有消息中出现过的语言
+    pub fn languages(&self) -> Vec<&str> {
+        if !self.supported_languages.is_empty() {
+            return self
+                .supported_languages
+                .iter()
+                .map(String::as_str)
+                .collect();
+        }
+
+        let mut languages = vec![self.default_language.as_str()];
+        for entry in &self.errors {
+            for (lang, _) in &entry.messages {
+                if !languages.contains(&lang.as_str()) {
+                    languages.push(lang);
+                }
+            }
+        }
+        languages
+    }
+
+    /// 按名称查找分类
+    pub fn category(&self, key: &str) -> Option<&CategoryEntry> {
+        self.categories.iter().find(|c| c.key == key)
+    }
+
+    /// 只包含指定分组错误码的目录（语言等配置保持不变），用于为分组生成独立的枚举
+    pub fn group_catalog(&self, group: &GroupEntry) -> ErrorCatalog {
+        ErrorCatalog {
+            // 保证各分组与汇总枚举的支持语言列表一致
+            supported_languages: self.languages().into_iter().map(String::from).collect(),
+            groups: Vec::new(),
+            errors: self
+                .errors
+                .iter()
+                .filter(|entry| entry.group.as_deref() == Some(group.key.as_str()))
+                .cloned()
+                .collect(),
+            ..self.clone()
+        }
+    }
+}
+
+impl CategoryEntry {
+    /// 错误码是否落在该分类的范围内
+    pub fn contains(&self, code: i32) -> bool {
+        (self.start..=self.end).contains(&code)
+    }
+}
+
+impl ErrorEntry {
+    /// 从配置构建错误码定义
+    fn build(
+        key: String,
+        def: ErrorDef,
+        group: Option<&str>,
+        categories: &[CategoryEntry],
+        default_lang: &str,
+    ) -> Self {
+        // 未显式声明分类时，按 code 所在范围推断
+        let category = def.category.or_else(|| {
+            categories
+                .iter()
+                .find(|c| c.contains(def.code))
+                .map(|c| c.key.clone())
+        });
+        let category_status = category
+            .as_deref()
+            .and_then(|name| categories.iter().find(|c| c.key == name))
+            .and_then(|c| c.http_status);
+
+        // 未声明 args 时按默认语言消息中占位符出现的顺序生成参数
+        let args = if def.args.is_empty() {
+            def.message
+                .get(default_lang)
+                .and_then(|msg| template::placeholders(msg).ok())
+                .unwrap_or_default()
+                .into_iter()
+                .map(|name| TemplateArg { name, ty: None })
+                .collect()
+        } else {
+            def.args
+                .into_iter()
+                .map(|(name, ty)| TemplateArg { name, ty: Some(ty) })
+                .collect()
+        };
+
+        ErrorEntry {
+            variant: to_pascal_case(&key),
+            key,
+            code: def.code,
+            http_status: def
+                .http_status
+                .or(category_status)
+                .unwrap_or(DEFAULT_HTTP_STATUS),
+            category,
+            group: group.map(String::from),
+            description: def.description,
+            args,
+            messages: def.message.into_iter().collect(),
+            file: None,
+            fluent: Vec::new(),
+        }
+    }
+
+    /// 获取指定语言的消息
+    pub fn message(&self, lang: &str) -> Option<&str> {
+        self.messages
+            .iter()
+            .find(|(l, _)| l == lang)
+            .map(|(_, m)| m.as_str())
+    }
+}
+
+/// 将 snake_case 转换为 PascalCase
+pub fn to_pascal_case(s: &str) -> String {
+    s.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,201 @@
+// 📦 脚手架 - 分配错误码、向配置文件中新增错误（`biz-error` 命令行工具的 `new` / `next-code`）
+//
+// 新增的错误插入到同一分类（错误码范围）中与它相邻的错误旁边，使配置文件保持按错误码排列：
+//
+// ```text
+// errors:
+//   invalid_param:   { code: 4000 }
+//   user_not_found:  { code: 4004 }   <── 插入到这里之后
+//   (new) user_locked: { code: 4005 }
+//   internal_error:  { code: 5000 }
+// ```
+//
+// 只改写新增的几行，文件其余部分保持不变；写入后重新加载并校验整个配置，失败时恢复原文件。
+
+use std::path::{Path, PathBuf};
+
+use crate::Error;
+use crate::model::{ErrorCatalog, ErrorEntry};
+use crate::schema::ConfigFormat;
+use crate::yaml_edit::{self, Position};
+
+/// 要新增的错误
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NewError {
+    /// 错误名（snake_case）
+    pub key: String,
+    /// 数字错误码
+    pub code: i32,
+    /// HTTP 状态码（`None` 时使用分类的默认值或 500）
+    pub http_status: Option<u16>,
+    /// 所属分组（`None` 时使用相邻错误所在的分组）
+    pub group: Option<String>,
+    /// 错误说明
+    pub description: Option<String>,
+    /// 多语言消息（语言, 消息）
+    pub messages: Vec<(String, String)>,
+}
+
+/// 分类中第一个未被使用的错误码
+pub fn next_code(catalog: &ErrorCatalog, category: &str) -> Result<i32, Error> {
+    let Some(entry) = catalog.categories.iter().find(|c| c.key == category) else {
+        let known: Vec<_> = catalog.categories.iter().map(|c| c.key.as_str()).collect();
+        let message = format!(
+            "unknown category `{}` (expected one of: {})",
+            category,
+            known.join(", ")
+        );
+        return Err(problem(catalog, message));
+    };
+    (entry.start..=entry.end)
+        .find(|code| !catalog.errors.iter().any(|e| e.code == *code))
+        .ok_or_else(|| {
+            let message = format!(
+                "category `{}` has no free code left in {}..={}",
+                category, entry.start, entry.end
+            );
+            problem(catalog, message)
+        })
+}
+
+/// 向配置文件中新增一个错误，返回被改写的文件
+///
+/// 配置必须能通过校验；新增后的配置也必须能通过校验（例如提供了所有必需语言的消息），
+/// 否则恢复原文件并返回校验错误。
+pub fn add_error(config: impl AsRef<Path>, error: &NewError) -> Result<PathBuf, Error> {
+    let config = config.as_ref();
+    let catalog = ErrorCatalog::from_path(config)?;
+
+    let mut problems = Vec::new();
+    if let Some(existing) = catalog.errors.iter().find(|e| e.key == error.key) {
+        problems.push(format!("error `{}` already exists (code {})", error.key, existing.code));
+    }
+    if let Some(existing) = catalog.errors.iter().find(|e| e.code == error.code) {
+        problems.push(format!("code {} is already used by `{}`", error.code, existing.key));
+    }
+    if let Some(ref group) = error.group
+        && !catalog.groups.iter().any(|g| g.key == *group)
+    {
+        problems.push(format!("unknown group `{}`", group));
+    }
+    if !problems.is_empty() {
+        return Err(Error::Validation {
+            path: Some(config.to_path_buf()),
+            problems,
+        });
+    }
+
+    let (group, position, file) = placement(&catalog, config, error)?;
+    if ConfigFormat::from_path(&file) != ConfigFormat::Yaml {
+        return Err(Error::Parse {
+            path: Some(file),
+            line: None,
+            column: None,
+            message: "adding errors is only supported for YAML config files".to_string(),
+        });
+    }
+
+    let original = std::fs::read_to_string(&file).map_err(|source| Error::Io {
+        path: file.clone(),
+        source,
+    })?;
+    let updated =
+        yaml_edit::insert_error(&original, group.as_deref(), position, &error.key, &body(error))
+            .map_err(|e| e.with_path(&file))?;
+    write(&file, &updated)?;
+
+    if let Err(err) = ErrorCatalog::from_path(config) {
+        write(&file, &original)?;
+        return Err(err);
+    }
+    Ok(file)
+}
+
+/// 新错误的分组、插入位置和所在文件
+fn placement<'a>(
+    catalog: &'a ErrorCatalog,
+    config: &Path,
+    error: &'a NewError,
+) -> Result<(Option<String>, Position<'a>, PathBuf), Error> {
+    let category = catalog
+        .categories
+        .iter()
+        .find(|c| (c.start..=c.end).contains(&error.code))
+        .map(|c| c.key.as_str());
+
+    // 候选的相邻错误：指定了分组时只在该分组中查找；优先同一分类
+    let candidates: Vec<&ErrorEntry> = catalog
+        .errors
+        .iter()
+        .filter(|e| error.group.is_none() || e.group == error.group)
+        .collect();
+    let same_category: Vec<&ErrorEntry> = candidates
+        .iter()
+        .copied()
+        .filter(|e| category.is_some() && e.category.as_deref() == category)
+        .collect();
+    let neighbor = [same_category, candidates].into_iter().find_map(|entries| {
+        let before = entries
+            .iter()
+            .filter(|e| e.code < error.code)
+            .max_by_key(|e| e.code)
+            .map(|e| (*e, Position::After(e.key.as_str())));
+        let after = || {
+            entries
+                .iter()
+                .filter(|e| e.code > error.code)
+                .min_by_key(|e| e.code)
+                .map(|e| (*e, Position::Before(e.key.as_str())))
+        };
+        before.or_else(after)
+    });
+
+    let default_file = || catalog.files.first().cloned().unwrap_or_else(|| config.to_path_buf());
+    match neighbor {
+        Some((entry, position)) => Ok((
+            entry.group.clone(),
+            position,
+            entry.file.clone().unwrap_or_else(default_file),
+        )),
+        None if error.group.is_none() && !catalog.groups.is_empty() => Err(Error::Validation {
+            path: Some(config.to_path_buf()),
+            problems: vec![format!(
+                "errors are organized in groups; choose a group for `{}`",
+                error.key
+            )],
+        }),
+        None => Ok((error.group.clone(), Position::End, default_file())),
+    }
+}
+
+/// 错误名下的各行（每层缩进两个空格）
+fn body(error: &NewError) -> Vec<String> {
+    let mut lines = vec![format!("code: {}", error.code)];
+    if let Some(status) = error.http_status {
+        lines.push(format!("http_status: {}", status));
+    }
+    if let Some(ref description) = error.description {
+        lines.push(format!("description: {}", yaml_edit::quote(description)));
+    }
+    if !error.messages.is_empty() {
+        lines.push("message:".to_string());
+        for (lang, message) in &error.messages {
+            lines.push(format!("  {}: {}", lang, yaml_edit::quote(message)));
+        }
+    }
+    lines
+}
+
+fn write(file: &Path, content: &str) -> Result<(), Error> {
+    std::fs::write(file, content).map_err(|source| Error::Io {
+        path: file.to_path_buf(),
+        source,
+    })
+}
+
+fn problem(catalog: &ErrorCatalog, message: String) -> Error {
+    Error::Validation {
+        path: catalog.files.first().cloned(),
+        problems: vec![message],
+    }
+}
@@ -0,0 +1,210 @@
+// 📦 配置文件结构 - 与 biz_errors.yaml 一一对应的 serde 类型
+//
+// 所有结构都启用 `deny_unknown_fields`，
+// 拼写错误（如 `http_stauts`、`mesage`）会直接报错而不是静默使用默认值
+//
+// YAML、JSON、TOML 三种格式共用同一套结构，按文件扩展名选择解析器：
+// - `.yaml` / `.yml` 及其他扩展名（如 `biz_errors.yaml.example`）：始终可用
+// - `.json`：需要 `json` feature
+// - `.toml`：需要 `toml` feature
+
+use std::path::Path;
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+use crate::Error;
+
+/// 配置文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    /// YAML（`.yaml` / `.yml`）
+    Yaml,
+    /// JSON（`.json`，需要 `json` feature）
+    Json,
+    /// TOML（`.toml`，需要 `toml` feature）
+    Toml,
+}
+
+impl ConfigFormat {
+    /// 按文件扩展名选择格式（不区分大小写，`.json` / `.toml` 以外的文件都按 YAML 解析）
+    pub fn from_path(path: &Path) -> Self {
+        let ext = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        match ext.as_deref() {
+            Some("json") => Self::Json,
+            Some("toml") => Self::Toml,
+            _ => Self::Yaml,
+        }
+    }
+}
+
+impl BizErrorConfig {
+    /// 按指定格式解析配置内容
+    pub fn parse(content: &str, format: ConfigFormat) -> Result<Self, Error> {
+        parse_as(content, format)
+    }
+}
+
+/// 按指定格式将配置内容解析为任意 serde 类型（根配置和被 include 的配置片段共用）
+pub(crate) fn parse_as<T: DeserializeOwned>(
+    content: &str,
+    format: ConfigFormat,
+) -> Result<T, Error> {
+    match format {
+        ConfigFormat::Yaml => serde_yaml::from_str(content).map_err(|e| Error::from_yaml(None, e)),
+        #[cfg(feature = "json")]
+        ConfigFormat::Json => serde_json::from_str(content).map_err(Error::from_json),
+        #[cfg(feature = "toml")]
+        ConfigFormat::Toml => toml::from_str(content).map_err(|e| Error::from_toml(content, e)),
+        #[allow(unreachable_patterns)]
+        _ => Err(Error::Parse {
+            path: None,
+            line: None,
+            column: None,
+            message: format!(
+                "{:?} config files require the `{}` feature of biz-error-codegen-core \
+                 (`codegen-{}` feature of biz-error)",
+                format,
+                format.feature(),
+                format.feature()
+            ),
+        }),
+    }
+}
+
+impl ConfigFormat {
+    /// 启用该格式所需的 feature 名
+    fn feature(self) -> &'static str {
+        match self {
+            Self::Yaml => "yaml",
+            Self::Json => "json",
+            Self::Toml => "toml",
+        }
+    }
+}
+
+/// biz_errors.yaml 顶层结构
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BizErrorConfig {
+    /// 默认语言（默认 `en`）
+    #[serde(default = "default_language")]
+    pub default_language: String,
+    /// 支持的语言列表（为空时不做翻译完整性检查）
+    #[serde(default)]
+    pub supported_languages: Vec<String>,
+    /// 语言回退链（请求的语言 -> 依次尝试的语言），如 `zh-HK: [zh-TW, zh-CN]`
+    #[serde(default)]
+    pub fallbacks: IndexMap<String, Vec<String>>,
+    /// 缺少某种支持语言的翻译时如何处理（默认 `error`）
+    #[serde(default)]
+    pub missing_translation: MissingTranslation,
+    /// RFC 9457 Problem Details 中 `type` 的前缀，与错误名拼接成完整 URI
+    #[serde(default)]
+    pub type_base_uri: Option<String>,
+    /// 错误码分类（键为 snake_case 分类名，保持文件中的顺序）
+    #[serde(default)]
+    pub categories: IndexMap<String, CategoryDef>,
+    /// 错误码定义（键为 snake_case 错误名，保持文件中的顺序）
+    #[serde(default)]
+    pub errors: IndexMap<String, ErrorDef>,
+    /// 错误码分组（键为 snake_case 分组名），每个分组生成一个独立的枚举，不能与 `errors` 同时使用
+    #[serde(default)]
+    pub groups: IndexMap<String, GroupDef>,
+    /// Fluent 资源（语言 -> `.ftl` 文件或内联内容），消息 ID 为错误名，需要 `fluent` feature
+    #[serde(default)]
+    pub fluent: IndexMap<String, FluentSource>,
+}
+
+/// 一种语言的 Fluent 资源
+///
+/// ```yaml
+/// fluent:
+///   ru: locales/ru.ftl        # 相对于配置文件所在目录
+///   ar:
+///     source: |
+///       user_not_found = المستخدم { $user_id } غير موجود
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum FluentSource {
+    /// `.ftl` 文件路径
+    File(String),
+    /// 内联的 Fluent 内容
+    Inline {
+        /// `.ftl` 内容
+        source: String,
+    },
+}
+
+/// 错误码分组的配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GroupDef {
+    /// 分组说明（作为生成的枚举的文档注释）
+    #[serde(default)]
+    pub description: Option<String>,
+    /// 该分组的错误码定义（键为 snake_case 错误名，保持文件中的顺序）
+    #[serde(default)]
+    pub errors: IndexMap<String, ErrorDef>,
+}
+
+/// 错误码分类的配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CategoryDef {
+    /// 错误码范围 `[起始, 结束]`（闭区间）
+    pub range: [i32; 2],
+    /// 该分类下错误的默认 HTTP 状态码
+    #[serde(default)]
+    pub http_status: Option<u16>,
+    /// 分类说明
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// 单个错误码的配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ErrorDef {
+    /// 数字错误码
+    pub code: i32,
+    /// HTTP 状态码（默认取所属分类的 http_status，否则为 500）
+    #[serde(default)]
+    pub http_status: Option<u16>,
+    /// 所属分类（省略时按 code 所在范围推断）
+    #[serde(default)]
+    pub category: Option<String>,
+    /// 错误说明（不出现在响应中，导出翻译文件时作为给翻译人员的备注）
+    #[serde(default)]
+    pub description: Option<String>,
+    /// 消息模板参数的类型（参数名 -> Rust 类型），同时决定生成的构造函数的参数顺序
+    ///
+    /// 省略时按默认语言消息中占位符出现的顺序生成参数，类型为 `impl Display`
+    #[serde(default)]
+    pub args: IndexMap<String, String>,
+    /// 多语言消息（语言 -> 消息），可以省略由 Fluent 资源提供的语言
+    #[serde(default)]
+    pub message: IndexMap<String, String>,
+}
+
+/// 缺少翻译时的处理策略
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MissingTranslation {
+    /// 编译失败
+    #[default]
+    Error,
+    /// 输出编译警告，运行时回退到默认语言
+    Warn,
+    /// 静默回退到默认语言
+    Fallback,
+}
+
+pub(crate) fn default_language() -> String {
+    "en".to_string()
+}
@@ -0,0 +1,45 @@
+// 📦 消息模板 - 解析消息中的 `{name}` 占位符
+//
+// 语法与 `biz_error::render_template` 保持一致：
+// - `{name}` 为占位符，name 必须是合法的 Rust 标识符
+// - `{{` 和 `}}` 分别转义为字面量 `{` 和 `}`
+
+/// 按首次出现的顺序返回模板中的占位符名（去重）
+pub fn placeholders(template: &str) -> Result<Vec<String>, String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(format!("unclosed placeholder `{{{}`", name)),
+                    }
+                }
+                if syn::parse_str::<syn::Ident>(&name).is_err() {
+                    return Err(format!(
+                        "placeholder `{{{}}}` is not a valid Rust identifier",
+                        name
+                    ));
+                }
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+            '}' => return Err("unmatched `}` (use `}}` for a literal brace)".to_string()),
+            _ => {}
+        }
+    }
+
+    Ok(names)
+}
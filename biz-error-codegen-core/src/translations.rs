@@ -0,0 +1,323 @@
+// 📦 翻译交换 - 导出 XLIFF 2.0 / gettext PO 给翻译人员，再把译文导入回配置文件
+//
+// ```text
+// biz_errors.yaml ──export_xliff / export_po──> zh-CN.xlf / zh-CN.po ──(CAT 工具翻译)──┐
+//        ▲                                                                          │
+//        └──────────────── import_translations (只改动 message 中对应的语言) ◄───────────┘
+// ```
+//
+// - 源语言为 `default_language`，每个错误导出为一个翻译单元：ID 为错误名，备注中带上错误码、错误名和 `description`
+// - 导入时只改写配置文件中对应的 `message` 条目，其余内容（顺序、注释、格式）保持不变；
+//   配置由多个文件合并而来时，改写定义该错误的文件
+// - 导出后源语言消息又被修改过的条目视为过期（stale），不会导入
+// - 译文的 `{name}` 占位符必须与该错误的参数一致，否则视为无效（invalid），不会导入
+// - 目标语言必须已声明；写入后整个配置必须仍能通过校验，否则恢复所有文件
+// - 来自 Fluent 资源的消息不参与导出和导入（复数等变体需要直接编辑 `.ftl`）
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::Error;
+use crate::model::{ErrorCatalog, ErrorEntry};
+use crate::schema::ConfigFormat;
+use crate::{template, yaml_edit};
+
+mod po;
+mod xliff;
+
+/// 从翻译文件中读取的译文
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Translations {
+    /// 源语言（XLIFF 的 `srcLang`，PO 文件中没有时为 `None`）
+    pub source_lang: Option<String>,
+    /// 目标语言
+    pub lang: String,
+    /// 翻译单元（保持文件中的顺序）
+    pub units: Vec<TranslationUnit>,
+}
+
+/// 一个翻译单元
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslationUnit {
+    /// 错误名
+    pub key: String,
+    /// 导出时的源语言消息
+    pub source: String,
+    /// 译文（未翻译时为 `None`）
+    pub target: Option<String>,
+}
+
+/// 导入结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    /// 写入了新译文的错误
+    pub updated: Vec<String>,
+    /// 译文与配置文件中已有的相同
+    pub unchanged: Vec<String>,
+    /// 导入后仍缺少该语言消息的错误（包括翻译文件中未翻译的条目）
+    pub untranslated: Vec<String>,
+    /// 导出后源语言消息又被修改过的条目（未导入）
+    pub stale: Vec<StaleEntry>,
+    /// 译文的占位符有误的条目（未导入）
+    pub invalid: Vec<InvalidEntry>,
+    /// 配置中已不存在的错误（未导入）
+    pub unknown: Vec<String>,
+    /// 被改写的配置文件
+    pub files: Vec<PathBuf>,
+}
+
+/// 过期的翻译单元
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleEntry {
+    /// 错误名
+    pub key: String,
+    /// 翻译文件中的源语言消息（导出时的内容）
+    pub exported: String,
+    /// 配置文件中当前的源语言消息
+    pub current: String,
+}
+
+/// 占位符有误的翻译单元
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidEntry {
+    /// 错误名
+    pub key: String,
+    /// 译文
+    pub target: String,
+    /// 问题描述
+    pub problem: String,
+}
+
+impl Translations {
+    /// 读取翻译文件（`.po` 按 gettext PO 解析，其他扩展名按 XLIFF 2.0 解析）
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let is_po = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("po"));
+        let result = if is_po {
+            Self::from_po(&content)
+        } else {
+            Self::from_xliff(&content)
+        };
+        result.map_err(|e| e.with_path(path))
+    }
+
+    /// 解析 XLIFF 2.0
+    pub fn from_xliff(content: &str) -> Result<Self, Error> {
+        xliff::parse(content)
+    }
+
+    /// 解析 gettext PO
+    pub fn from_po(content: &str) -> Result<Self, Error> {
+        po::parse(content)
+    }
+}
+
+/// 导出指定语言的 XLIFF 2.0 文件内容（源语言为 `default_language`，已有的译文作为 `<target>`）
+pub fn export_xliff(catalog: &ErrorCatalog, lang: &str) -> String {
+    xliff::write(catalog, lang, &exported_entries(catalog, lang))
+}
+
+/// 导出指定语言的 gettext PO 文件内容（`msgctxt` 为错误名，`msgid` 为源语言消息）
+pub fn export_po(catalog: &ErrorCatalog, lang: &str) -> String {
+    po::write(catalog, lang, &exported_entries(catalog, lang))
+}
+
+/// 导出的条目：(错误, 源语言消息, 已有的译文)，跳过源语言或目标语言来自 Fluent 的错误
+fn exported_entries<'a>(
+    catalog: &'a ErrorCatalog,
+    lang: &str,
+) -> Vec<(&'a ErrorEntry, &'a str, Option<&'a str>)> {
+    let source_lang = catalog.default_language.as_str();
+    catalog
+        .errors
+        .iter()
+        .filter(|entry| !entry.fluent.iter().any(|l| l == source_lang || l == lang))
+        .filter_map(|entry| {
+            let source = entry.message(source_lang)?;
+            Some((entry, source, entry.message(lang)))
+        })
+        .collect()
+}
+
+/// 将译文导入配置文件
+///
+/// 只支持 YAML 配置。配置文件（包括 include 的文件）中只有对应的 `message` 条目会被改写。
+/// 目标语言必须已在 `supported_languages` 中声明（声明了该列表时）；
+/// 写入后重新加载并校验整个配置，失败时恢复所有被改写的文件并返回校验错误。
+pub fn import_translations(
+    config: impl AsRef<Path>,
+    translations: &Translations,
+) -> Result<ImportReport, Error> {
+    let config = config.as_ref();
+    let catalog = ErrorCatalog::from_path_unvalidated(config)?;
+    let lang = translations.lang.as_str();
+    let source_lang = catalog.default_language.as_str();
+    if let Some(ref exported) = translations.source_lang
+        && exported != source_lang
+    {
+        return Err(Error::Validation {
+            path: Some(config.to_path_buf()),
+            problems: vec![format!(
+                "translations were exported from `{}`, but default_language is `{}`",
+                exported, source_lang
+            )],
+        });
+    }
+    let supported = &catalog.supported_languages;
+    if !supported.is_empty() && !supported.iter().any(|l| l == lang) {
+        return Err(Error::Validation {
+            path: Some(config.to_path_buf()),
+            problems: vec![format!(
+                "`{}` is not listed in supported_languages; declare it before importing its translations",
+                lang
+            )],
+        });
+    }
+
+    let mut report = ImportReport::default();
+    // 文件 -> 该文件中要写入的消息
+    let mut edits: Vec<(PathBuf, Vec<yaml_edit::MessageEdit>)> = Vec::new();
+    let mut translated: Vec<&str> = Vec::new();
+
+    for unit in &translations.units {
+        let Some(entry) = catalog.errors.iter().find(|e| e.key == unit.key) else {
+            report.unknown.push(unit.key.clone());
+            continue;
+        };
+        if entry.fluent.iter().any(|l| l == lang || l == source_lang) {
+            continue;
+        }
+        let current = entry.message(source_lang).unwrap_or_default();
+        if current != unit.source {
+            report.stale.push(StaleEntry {
+                key: unit.key.clone(),
+                exported: unit.source.clone(),
+                current: current.to_string(),
+            });
+            continue;
+        }
+        let Some(ref target) = unit.target else {
+            continue;
+        };
+        if let Err(problem) = check_placeholders(entry, target) {
+            report.invalid.push(InvalidEntry {
+                key: unit.key.clone(),
+                target: target.clone(),
+                problem,
+            });
+            continue;
+        }
+
+        translated.push(&entry.key);
+        if entry.message(lang) == Some(target.as_str()) {
+            report.unchanged.push(entry.key.clone());
+            continue;
+        }
+
+        let file = entry
+            .file
+            .clone()
+            .or_else(|| catalog.files.first().cloned())
+            .unwrap_or_else(|| config.to_path_buf());
+        let edit = yaml_edit::MessageEdit {
+            group: entry.group.clone(),
+            key: entry.key.clone(),
+            lang: lang.to_string(),
+            message: target.clone(),
+            messages: entry
+                .messages
+                .iter()
+                .filter(|(l, _)| !entry.fluent.contains(l))
+                .cloned()
+                .collect(),
+        };
+        match edits.iter_mut().find(|(f, _)| *f == file) {
+            Some((_, file_edits)) => file_edits.push(edit),
+            None => edits.push((file, vec![edit])),
+        }
+        report.updated.push(entry.key.clone());
+    }
+
+    report.untranslated = catalog
+        .errors
+        .iter()
+        .filter(|entry| entry.message(lang).is_none() && !translated.contains(&entry.key.as_str()))
+        .map(|entry| entry.key.clone())
+        .collect();
+
+    // 先在内存中改写所有文件，全部成功后再写回
+    let mut originals: HashMap<PathBuf, String> = HashMap::new();
+    let mut outputs: HashMap<PathBuf, String> = HashMap::new();
+    for (file, file_edits) in &edits {
+        if ConfigFormat::from_path(file) != ConfigFormat::Yaml {
+            return Err(Error::Parse {
+                path: Some(file.clone()),
+                line: None,
+                column: None,
+                message: "importing translations is only supported for YAML config files"
+                    .to_string(),
+            });
+        }
+        let content = std::fs::read_to_string(file).map_err(|source| Error::Io {
+            path: file.clone(),
+            source,
+        })?;
+        let updated = yaml_edit::set_messages(&content, file_edits).map_err(|e| e.with_path(file))?;
+        outputs.insert(file.clone(), updated);
+        originals.insert(file.clone(), content);
+    }
+
+    // 写回后校验合并的配置；写入或校验失败时恢复已改写的文件，不留下只导入了一部分的配置
+    for (file, _) in edits {
+        if let Err(err) = write(&file, &outputs[&file]) {
+            restore(&report.files, &originals)?;
+            return Err(err);
+        }
+        report.files.push(file);
+    }
+    if !report.files.is_empty()
+        && let Err(err) = ErrorCatalog::from_path(config)
+    {
+        restore(&report.files, &originals)?;
+        return Err(err);
+    }
+
+    Ok(report)
+}
+
+fn write(file: &Path, content: &str) -> Result<(), Error> {
+    std::fs::write(file, content).map_err(|source| Error::Io {
+        path: file.to_path_buf(),
+        source,
+    })
+}
+
+/// 恢复被改写的文件
+fn restore(files: &[PathBuf], originals: &HashMap<PathBuf, String>) -> Result<(), Error> {
+    for file in files {
+        write(file, &originals[file])?;
+    }
+    Ok(())
+}
+
+/// 译文的占位符必须与错误的参数（即源语言消息的占位符）一致
+fn check_placeholders(entry: &ErrorEntry, target: &str) -> Result<(), String> {
+    let mut names = template::placeholders(target)?;
+    names.sort_unstable();
+    let mut expected: Vec<&str> = entry.args.iter().map(|a| a.name.as_str()).collect();
+    expected.sort_unstable();
+    if names != expected {
+        return Err(format!(
+            "uses placeholders {{{}}}, expected {{{}}}",
+            names.join(", "),
+            expected.join(", ")
+        ));
+    }
+    Ok(())
+}
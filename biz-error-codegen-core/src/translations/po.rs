@@ -0,0 +1,238 @@
+// 📦 gettext PO - `msgctxt` 为错误名，`msgid` 为源语言消息，错误码、错误名和说明写在 `#.` 注释中
+//
+// ```po
+// #. code: 4004
+// #. key: user_not_found
+// #. description: 按 ID 查找用户失败
+// msgctxt "user_not_found"
+// msgid "User {user_id} not found"
+// msgstr "用户 {user_id} 不存在"
+// ```
+//
+// 头部的 `Language` 为目标语言，`X-Source-Language` 为源语言；
+// `msgstr` 为空或带有 `#, fuzzy` 标记的条目视为未翻译。
+
+use std::fmt::Write;
+
+use super::{TranslationUnit, Translations};
+use crate::Error;
+use crate::model::{ErrorCatalog, ErrorEntry};
+
+/// 生成 PO 文件内容
+pub(super) fn write(
+    catalog: &ErrorCatalog,
+    lang: &str,
+    entries: &[(&ErrorEntry, &str, Option<&str>)],
+) -> String {
+    let mut out = String::from("msgid \"\"\nmsgstr \"\"\n");
+    out.push_str("\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+    let _ = writeln!(out, "\"Language: {}\\n\"", escape(lang));
+    let _ = writeln!(
+        out,
+        "\"X-Source-Language: {}\\n\"",
+        escape(&catalog.default_language)
+    );
+
+    for (entry, source, target) in entries {
+        out.push('\n');
+        let _ = writeln!(out, "#. code: {}", entry.code);
+        let _ = writeln!(out, "#. key: {}", entry.key);
+        if let Some(ref description) = entry.description {
+            for line in description.lines() {
+                let _ = writeln!(out, "#. description: {}", line);
+            }
+        }
+        write_field(&mut out, "msgctxt", &entry.key);
+        write_field(&mut out, "msgid", source);
+        write_field(&mut out, "msgstr", target.unwrap_or_default());
+    }
+    out
+}
+
+/// 写入一个字段，包含换行的字符串按 gettext 惯例拆成多行
+fn write_field(out: &mut String, keyword: &str, value: &str) {
+    if !value.trim_end_matches('\n').contains('\n') {
+        let _ = writeln!(out, "{} \"{}\"", keyword, escape(value));
+        return;
+    }
+    let _ = writeln!(out, "{} \"\"", keyword);
+    for line in value.split_inclusive('\n') {
+        let _ = writeln!(out, "\"{}\"", escape(line));
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// 正在读取的条目
+#[derive(Default)]
+struct Entry {
+    /// 条目开始的行号
+    line: usize,
+    fuzzy: bool,
+    context: Option<String>,
+    id: Option<String>,
+    value: Option<String>,
+}
+
+/// 当前续行所属的字段
+#[derive(Clone, Copy)]
+enum Field {
+    Context,
+    Id,
+    Value,
+    /// `msgid_plural`、`msgstr[1]` 等只读取不使用的字段
+    Ignored,
+}
+
+/// 解析 PO 文件内容
+pub(super) fn parse(content: &str) -> Result<Translations, Error> {
+    let mut source_lang = None;
+    let mut lang = None;
+    let mut units = Vec::new();
+    let mut entry = Entry::default();
+    let mut field = None;
+
+    let mut finish = |entry: Entry| -> Result<(), Error> {
+        let Some(id) = entry.id else {
+            return Ok(());
+        };
+        let value = entry.value.unwrap_or_default();
+        let Some(key) = entry.context else {
+            if !id.is_empty() {
+                return Err(error(entry.line, "entry has no `msgctxt` (expected the error name)"));
+            }
+            // 头部：`Name: value` 逐行排列
+            for header in value.lines() {
+                if let Some((name, value)) = header.split_once(':') {
+                    match name.trim() {
+                        "Language" => lang = Some(value.trim().to_string()),
+                        "X-Source-Language" => source_lang = Some(value.trim().to_string()),
+                        _ => {}
+                    }
+                }
+            }
+            return Ok(());
+        };
+        units.push(TranslationUnit {
+            key,
+            source: id,
+            target: (!entry.fuzzy && !value.is_empty()).then_some(value),
+        });
+        Ok(())
+    };
+
+    for (index, raw) in content.lines().enumerate() {
+        let line_no = index + 1;
+        let line = raw.trim();
+        if line.is_empty() {
+            finish(std::mem::take(&mut entry))?;
+            field = None;
+            continue;
+        }
+        if let Some(flags) = line.strip_prefix("#,") {
+            if entry.id.is_some() {
+                finish(std::mem::take(&mut entry))?;
+            }
+            entry.fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+            continue;
+        }
+        // 其他注释（包括 `#~` 废弃条目）
+        if line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('"') {
+            let value = unquote(line, line_no)?;
+            match field {
+                Some(Field::Context) => entry.context.get_or_insert_default().push_str(&value),
+                Some(Field::Id) => entry.id.get_or_insert_default().push_str(&value),
+                Some(Field::Value) => entry.value.get_or_insert_default().push_str(&value),
+                Some(Field::Ignored) => {}
+                None => return Err(error(line_no, "string without a keyword")),
+            }
+            continue;
+        }
+
+        let (keyword, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let next = match keyword {
+            "msgctxt" => Field::Context,
+            "msgid" => Field::Id,
+            "msgstr" | "msgstr[0]" => Field::Value,
+            "msgid_plural" => Field::Ignored,
+            k if k.starts_with("msgstr[") => Field::Ignored,
+            _ => return Err(error(line_no, &format!("unknown keyword `{}`", keyword))),
+        };
+        // 上一条目没有空行分隔时，新的 msgctxt / msgid 开始新条目
+        let starts_entry = matches!(next, Field::Context)
+            || matches!(next, Field::Id) && entry.context.is_none();
+        if starts_entry && entry.id.is_some() {
+            finish(std::mem::take(&mut entry))?;
+        }
+        if entry.context.is_none() && entry.id.is_none() {
+            entry.line = line_no;
+        }
+        let value = unquote(rest.trim(), line_no)?;
+        match next {
+            Field::Context => entry.context = Some(value),
+            Field::Id => entry.id = Some(value),
+            Field::Value => entry.value = Some(value),
+            Field::Ignored => {}
+        }
+        field = Some(next);
+    }
+    finish(entry)?;
+
+    let lang = lang.ok_or_else(|| error(1, "PO header has no `Language`"))?;
+    Ok(Translations {
+        source_lang,
+        lang,
+        units,
+    })
+}
+
+/// 解析一个带引号的 PO 字符串
+fn unquote(s: &str, line: usize) -> Result<String, Error> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .filter(|_| s.len() >= 2)
+        .ok_or_else(|| error(line, "expected a quoted string"))?;
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some(c @ ('"' | '\\')) => out.push(c),
+            Some(c) => return Err(error(line, &format!("unknown escape `\\{}`", c))),
+            None => return Err(error(line, "unterminated escape")),
+        }
+    }
+    Ok(out)
+}
+
+fn error(line: usize, message: &str) -> Error {
+    Error::Parse {
+        path: None,
+        line: Some(line),
+        column: None,
+        message: message.to_string(),
+    }
+}
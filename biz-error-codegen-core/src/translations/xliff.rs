@@ -0,0 +1,213 @@
+// 📦 XLIFF 2.0 - 每个错误一个 `<unit>`，错误码、错误名和说明放在 `<notes>` 中
+//
+// ```xml
+// <unit id="user_not_found">
+//   <notes>
+//     <note category="code">4004</note>
+//     <note category="key">user_not_found</note>
+//     <note category="description">按 ID 查找用户失败</note>
+//   </notes>
+//   <segment state="translated">
+//     <source>User {user_id} not found</source>
+//     <target>用户 {user_id} 不存在</target>
+//   </segment>
+// </unit>
+// ```
+
+use std::fmt::Write;
+
+use quick_xml::Reader;
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+
+use super::{TranslationUnit, Translations};
+use crate::Error;
+use crate::model::{ErrorCatalog, ErrorEntry};
+
+const NAMESPACE: &str = "urn:oasis:names:tc:xliff:document:2.0";
+
+/// 生成 XLIFF 2.0 文件内容
+pub(super) fn write(
+    catalog: &ErrorCatalog,
+    lang: &str,
+    entries: &[(&ErrorEntry, &str, Option<&str>)],
+) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        out,
+        "<xliff xmlns=\"{}\" version=\"2.0\" srcLang=\"{}\" trgLang=\"{}\">",
+        NAMESPACE,
+        escape(catalog.default_language.as_str()),
+        escape(lang)
+    );
+    out.push_str("  <file id=\"biz_errors\">\n");
+    for (entry, source, target) in entries {
+        let _ = writeln!(out, "    <unit id=\"{}\">", escape(entry.key.as_str()));
+        out.push_str("      <notes>\n");
+        let _ = writeln!(out, "        <note category=\"code\">{}</note>", entry.code);
+        let _ = writeln!(
+            out,
+            "        <note category=\"key\">{}</note>",
+            escape(entry.key.as_str())
+        );
+        if let Some(ref description) = entry.description {
+            let _ = writeln!(
+                out,
+                "        <note category=\"description\">{}</note>",
+                escape(description.as_str())
+            );
+        }
+        out.push_str("      </notes>\n");
+        let state = if target.is_some() { "translated" } else { "initial" };
+        let _ = writeln!(out, "      <segment state=\"{}\">", state);
+        let _ = writeln!(out, "        <source>{}</source>", escape(*source));
+        if let Some(target) = target {
+            let _ = writeln!(out, "        <target>{}</target>", escape(*target));
+        }
+        out.push_str("      </segment>\n");
+        out.push_str("    </unit>\n");
+    }
+    out.push_str("  </file>\n");
+    out.push_str("</xliff>\n");
+    out
+}
+
+/// 当前正在读取的元素
+enum Text {
+    None,
+    Source,
+    Target,
+}
+
+/// 解析 XLIFF 2.0 文件内容
+///
+/// 一个 `<unit>` 中有多个 `<segment>` 时依次拼接；`<target>` 缺失或为空视为未翻译。
+pub(super) fn parse(content: &str) -> Result<Translations, Error> {
+    let mut reader = Reader::from_str(content);
+    let mut langs: Option<(Option<String>, String)> = None;
+    let mut units = Vec::new();
+    let mut unit: Option<(String, String, String)> = None;
+    let mut text = Text::None;
+
+    loop {
+        let position = reader.buffer_position();
+        let event = reader
+            .read_event()
+            .map_err(|e| parse_error(content, reader.error_position(), e))?;
+        match event {
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                match e.local_name().as_ref() {
+                    b"xliff" => {
+                        let source_lang = attribute(e, "srcLang")
+                            .map_err(|e| parse_error(content, position, e))?;
+                        let lang = attribute(e, "trgLang")
+                            .map_err(|e| parse_error(content, position, e))?
+                            .ok_or_else(|| {
+                                missing(content, position, "<xliff> has no `trgLang` attribute")
+                            })?;
+                        langs = Some((source_lang, lang));
+                    }
+                    b"unit" => {
+                        let key = attribute(e, "id")
+                            .map_err(|e| parse_error(content, position, e))?
+                            .ok_or_else(|| {
+                                missing(content, position, "<unit> has no `id` attribute")
+                            })?;
+                        unit = Some((key, String::new(), String::new()));
+                    }
+                    b"source" if unit.is_some() => text = Text::Source,
+                    b"target" if unit.is_some() => text = Text::Target,
+                    _ => {}
+                }
+                // `<source/>` 等空元素没有对应的结束事件
+                if matches!(event, Event::Empty(_)) {
+                    text = Text::None;
+                }
+            }
+            Event::End(ref e) => match e.local_name().as_ref() {
+                b"source" | b"target" => text = Text::None,
+                b"unit" => {
+                    if let Some((key, source, target)) = unit.take() {
+                        units.push(TranslationUnit {
+                            key,
+                            source,
+                            target: (!target.is_empty()).then_some(target),
+                        });
+                    }
+                }
+                _ => {}
+            },
+            Event::Text(ref t) => {
+                let value = t
+                    .unescape()
+                    .map_err(|e| parse_error(content, position, e))?;
+                push_text(&mut unit, &text, &value);
+            }
+            Event::CData(ref c) => {
+                let value = c
+                    .decode()
+                    .map_err(|e| parse_error(content, position, e))?;
+                push_text(&mut unit, &text, &value);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    let (source_lang, lang) =
+        langs.ok_or_else(|| missing(content, 0, "not an XLIFF 2.0 document (no <xliff> element)"))?;
+    Ok(Translations {
+        source_lang,
+        lang,
+        units,
+    })
+}
+
+fn push_text(unit: &mut Option<(String, String, String)>, text: &Text, value: &str) {
+    if let Some((_, source, target)) = unit {
+        match text {
+            Text::Source => source.push_str(value),
+            Text::Target => target.push_str(value),
+            Text::None => {}
+        }
+    }
+}
+
+fn attribute(e: &BytesStart<'_>, name: &str) -> Result<Option<String>, quick_xml::Error> {
+    match e.try_get_attribute(name)? {
+        Some(attr) => Ok(Some(attr.unescape_value()?.into_owned())),
+        None => Ok(None),
+    }
+}
+
+fn missing(content: &str, position: u64, message: &str) -> Error {
+    let (line, column) = line_column(content, position);
+    Error::Parse {
+        path: None,
+        line: Some(line),
+        column: Some(column),
+        message: message.to_string(),
+    }
+}
+
+fn parse_error(content: &str, position: u64, err: impl std::fmt::Display) -> Error {
+    let (line, column) = line_column(content, position);
+    Error::Parse {
+        path: None,
+        line: Some(line),
+        column: Some(column),
+        message: err.to_string(),
+    }
+}
+
+/// 字节位置对应的行列号（从 1 开始）
+fn line_column(content: &str, position: u64) -> (usize, usize) {
+    let mut end = (position as usize).min(content.len());
+    while !content.is_char_boundary(end) {
+        end -= 1;
+    }
+    let before = &content[..end];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+    (line, column)
+}
@@ -0,0 +1,445 @@
+// 📦 配置校验 - 在编译期拒绝会导致运行时错误或歧义的配置
+//
+// - 重复的数字错误码
+// - 转换为 PascalCase 后冲突的错误名（如 `not_found` 与 `not__found`）
+// - 无法作为 Rust 标识符的错误名（如 `404_error`）
+// - 超出 100-599 范围的 HTTP 状态码
+// - 分类范围重叠、错误码不在所属分类的范围内
+// - 分组与顶层 errors 混用、不同分组中的同名错误、无法作为 Rust 标识符的分组名
+// - 非法的消息模板，以及各语言消息使用的占位符不一致
+// - 没有任何消息的错误
+// - 不对应任何错误的 Fluent 消息、与普通消息重复的 Fluent 消息、引用了未声明参数的 Fluent 消息
+// - 回退链中引用了未知的语言
+// - 未在 supported_languages 中声明的语言，以及缺失的翻译（按 missing_translation 策略处理）
+
+use std::collections::HashMap;
+
+use crate::model::{ErrorCatalog, ErrorEntry, FluentEntry};
+use crate::schema::MissingTranslation;
+use crate::template;
+
+/// 合法的 HTTP 状态码范围
+const HTTP_STATUS_RANGE: std::ops::RangeInclusive<u16> = 100..=599;
+
+/// 生成的 `ErrorCode` 上已有的方法名，带参数的错误不能使用这些名字作为构造函数名
+const RESERVED_METHODS: &[&str] = &[
+    "code",
+    "key",
+    "message",
+    "message_lang",
+    "resolve_lang",
+    "supported_languages",
+    "default_language",
+    "fallbacks",
+    "type_base_uri",
+    "fluent_resource",
+    "http_status",
+    "http_status_code",
+    "category",
+];
+
+/// 校验结果
+#[derive(Debug, Default)]
+pub(crate) struct Findings {
+    /// 必须修复的问题（非空时拒绝生成代码）
+    pub problems: Vec<String>,
+    /// 警告
+    pub warnings: Vec<String>,
+}
+
+/// 校验错误码目录
+pub(crate) fn validate(catalog: &ErrorCatalog) -> Findings {
+    let mut findings = Findings::default();
+    let problems = &mut findings.problems;
+    let mut codes: HashMap<i32, &ErrorEntry> = HashMap::new();
+    // 变体名只需在所属枚举（分组）内唯一
+    let mut variants: HashMap<(Option<&str>, &str), &ErrorEntry> = HashMap::new();
+
+    for entry in &catalog.errors {
+        match codes.get(&entry.code) {
+            Some(first) => problems.push(format!(
+                "duplicate code {}: errors {} and {} both use it",
+                entry.code,
+                describe_entry(first),
+                describe_entry(entry)
+            )),
+            None => {
+                codes.insert(entry.code, entry);
+            }
+        }
+
+        if syn::parse_str::<syn::Ident>(&entry.variant).is_err() {
+            problems.push(format!(
+                "error `{}` maps to `{}`, which is not a valid Rust identifier",
+                entry.key, entry.variant
+            ));
+        } else if let Some(first) = variants.get(&(entry.group.as_deref(), entry.variant.as_str())) {
+            problems.push(format!(
+                "duplicate variant `{}`: errors {} and {} both map to it",
+                entry.variant,
+                describe_entry(first),
+                describe_entry(entry)
+            ));
+        } else {
+            variants.insert((entry.group.as_deref(), &entry.variant), entry);
+        }
+
+        if !HTTP_STATUS_RANGE.contains(&entry.http_status) {
+            problems.push(format!(
+                "error `{}` has invalid http_status {} (expected {}-{})",
+                entry.key,
+                entry.http_status,
+                HTTP_STATUS_RANGE.start(),
+                HTTP_STATUS_RANGE.end()
+            ));
+        }
+    }
+
+    if let Some(ref uri) = catalog.type_base_uri
+        && !is_absolute_uri(uri)
+    {
+        problems.push(format!(
+            "type_base_uri `{}` is not an absolute URI (expected e.g. `https://example.com/errors/`)",
+            uri
+        ));
+    }
+
+    check_categories(catalog, &mut findings);
+    check_groups(catalog, &mut findings);
+    check_templates(catalog, &mut findings);
+    check_fluent(catalog, &mut findings);
+    check_fallbacks(catalog, &mut findings);
+    check_translations(catalog, &mut findings);
+    findings
+}
+
+/// 错误名，配置由多个文件合并而来时附带所在文件
+fn describe_entry(entry: &ErrorEntry) -> String {
+    match entry.file {
+        Some(ref file) => format!("`{}` ({})", entry.key, file.display()),
+        None => format!("`{}`", entry.key),
+    }
+}
+
+/// 检查消息模板：各语言占位符一致、与声明的参数一致、构造函数名可用
+fn check_templates(catalog: &ErrorCatalog, findings: &mut Findings) {
+    let problems = &mut findings.problems;
+
+    for entry in &catalog.errors {
+        let mut expected: Vec<&str> = entry.args.iter().map(|a| a.name.as_str()).collect();
+        expected.sort_unstable();
+
+        if entry.messages.is_empty() {
+            problems.push(format!("error `{}` has no messages", entry.key));
+        }
+
+        for (lang, msg) in &entry.messages {
+            // Fluent 消息的变量由 check_fluent 检查（各变体可以只使用部分参数）
+            if entry.fluent.contains(lang) {
+                continue;
+            }
+            let mut names = match template::placeholders(msg) {
+                Ok(names) => names,
+                Err(e) => {
+                    problems.push(format!(
+                        "error `{}` has an invalid `{}` message: {}",
+                        entry.key, lang, e
+                    ));
+                    continue;
+                }
+            };
+            names.sort_unstable();
+            if names != expected {
+                problems.push(format!(
+                    "error `{}`: `{}` message uses placeholders {}, expected {}",
+                    entry.key,
+                    lang,
+                    describe(names.iter().map(String::as_str)),
+                    describe(expected.iter().copied())
+                ));
+            }
+        }
+
+        for arg in &entry.args {
+            if let Some(ty) = &arg.ty
+                && syn::parse_str::<syn::Type>(ty).is_err()
+            {
+                problems.push(format!(
+                    "error `{}` declares argument `{}` with invalid type `{}`",
+                    entry.key, arg.name, ty
+                ));
+            }
+        }
+
+        if !entry.args.is_empty() {
+            if syn::parse_str::<syn::Ident>(&entry.key).is_err() {
+                problems.push(format!(
+                    "error `{}` has message arguments, but its name is not a valid Rust identifier for the generated constructor",
+                    entry.key
+                ));
+            } else if RESERVED_METHODS.contains(&entry.key.as_str()) {
+                problems.push(format!(
+                    "error `{}` has message arguments, but its constructor would clash with `ErrorCode::{}`",
+                    entry.key, entry.key
+                ));
+            }
+        }
+    }
+}
+
+/// 检查 Fluent 资源：每条消息对应一个错误、不与同语言的普通消息重复、引用的变量都是该错误的参数
+fn check_fluent(catalog: &ErrorCatalog, findings: &mut Findings) {
+    let problems = &mut findings.problems;
+
+    for resource in &catalog.fluent {
+        let source = describe_resource(resource);
+        for message in &resource.messages {
+            let Some(entry) = catalog.errors.iter().find(|e| e.key == message.id) else {
+                problems.push(format!(
+                    "Fluent message `{}` in {} does not match any error",
+                    message.id, source
+                ));
+                continue;
+            };
+            if message.template.is_none() {
+                problems.push(format!(
+                    "Fluent message `{}` in {} has no value",
+                    message.id, source
+                ));
+                continue;
+            }
+            if !entry.fluent.contains(&resource.lang) {
+                problems.push(format!(
+                    "error `{}` has both a `{}` message and a Fluent message in {}",
+                    entry.key, resource.lang, source
+                ));
+                continue;
+            }
+
+            for name in &message.variables {
+                if syn::parse_str::<syn::Ident>(name).is_err() {
+                    problems.push(format!(
+                        "Fluent message `{}` in {} uses `${}`, which is not a valid Rust identifier",
+                        message.id, source, name
+                    ));
+                } else if !entry.args.iter().any(|arg| arg.name == *name) {
+                    problems.push(format!(
+                        "Fluent message `{}` in {} uses `${}`, which is not an argument of the error (expected {})",
+                        message.id,
+                        source,
+                        name,
+                        describe(entry.args.iter().map(|arg| arg.name.as_str()))
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Fluent 资源的描述，如 `` `ru` resource (locales/ru.ftl) ``
+fn describe_resource(resource: &FluentEntry) -> String {
+    match resource.file {
+        Some(ref file) => format!("`{}` resource ({})", resource.lang, file.display()),
+        None => format!("`{}` resource", resource.lang),
+    }
+}
+
+/// 将占位符列表格式化为 `{a, b}`
+fn describe<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    format!("{{{}}}", names.collect::<Vec<_>>().join(", "))
+}
+
+/// 检查分类声明及每个错误的分类归属
+fn check_categories(catalog: &ErrorCatalog, findings: &mut Findings) {
+    let problems = &mut findings.problems;
+    let mut variants: HashMap<&str, &str> = HashMap::new();
+
+    for (i, category) in catalog.categories.iter().enumerate() {
+        if syn::parse_str::<syn::Ident>(&category.variant).is_err() {
+            problems.push(format!(
+                "category `{}` maps to `{}`, which is not a valid Rust identifier",
+                category.key, category.variant
+            ));
+        } else if let Some(first) = variants.get(category.variant.as_str()) {
+            problems.push(format!(
+                "duplicate category variant `{}`: categories `{}` and `{}` both map to it",
+                category.variant, first, category.key
+            ));
+        } else {
+            variants.insert(&category.variant, &category.key);
+        }
+
+        if category.start > category.end {
+            problems.push(format!(
+                "category `{}` has an empty range {}-{}",
+                category.key, category.start, category.end
+            ));
+        }
+
+        if let Some(status) = category.http_status
+            && !HTTP_STATUS_RANGE.contains(&status)
+        {
+            problems.push(format!(
+                "category `{}` has invalid http_status {} (expected {}-{})",
+                category.key,
+                status,
+                HTTP_STATUS_RANGE.start(),
+                HTTP_STATUS_RANGE.end()
+            ));
+        }
+
+        for other in &catalog.categories[..i] {
+            if category.start <= other.end && other.start <= category.end {
+                problems.push(format!(
+                    "categories `{}` ({}-{}) and `{}` ({}-{}) have overlapping ranges",
+                    other.key, other.start, other.end, category.key, category.start, category.end
+                ));
+            }
+        }
+    }
+
+    for entry in &catalog.errors {
+        match entry.category.as_deref() {
+            None if !catalog.categories.is_empty() => problems.push(format!(
+                "error `{}` has code {}, which does not fall in any declared category",
+                entry.key, entry.code
+            )),
+            None => {}
+            Some(name) => match catalog.category(name) {
+                None => problems.push(format!(
+                    "error `{}` refers to unknown category `{}`",
+                    entry.key, name
+                )),
+                Some(category) if !category.contains(entry.code) => problems.push(format!(
+                    "error `{}` has code {}, outside the range {}-{} of category `{}`",
+                    entry.key, entry.code, category.start, category.end, category.key
+                )),
+                Some(_) => {}
+            },
+        }
+    }
+}
+
+/// 检查分组声明：不能与顶层 errors 混用，错误名在所有分组中唯一（用于 `key()` 和 Problem Details 的 type）
+fn check_groups(catalog: &ErrorCatalog, findings: &mut Findings) {
+    if catalog.groups.is_empty() {
+        return;
+    }
+    let problems = &mut findings.problems;
+
+    let ungrouped: Vec<_> = catalog
+        .errors
+        .iter()
+        .filter(|entry| entry.group.is_none())
+        .map(|entry| format!("`{}`", entry.key))
+        .collect();
+    if !ungrouped.is_empty() {
+        problems.push(format!(
+            "top-level errors cannot be used together with groups; move {} into a group (e.g. `common`)",
+            ungrouped.join(", ")
+        ));
+    }
+
+    let mut names: HashMap<&str, &str> = HashMap::new();
+    for group in &catalog.groups {
+        if syn::parse_str::<syn::Ident>(&group.name).is_err() {
+            problems.push(format!(
+                "group `{}` maps to `{}`, which is not a valid Rust identifier",
+                group.key, group.name
+            ));
+        } else if let Some(first) = names.get(group.name.as_str()) {
+            problems.push(format!(
+                "duplicate group enum `{}`: groups `{}` and `{}` both map to it",
+                group.name, first, group.key
+            ));
+        } else {
+            names.insert(&group.name, &group.key);
+        }
+    }
+
+    let mut keys: HashMap<&str, &ErrorEntry> = HashMap::new();
+    for entry in &catalog.errors {
+        match keys.get(entry.key.as_str()) {
+            Some(first) => problems.push(format!(
+                "error `{}` is defined in both group `{}` and group `{}`",
+                entry.key,
+                first.group.as_deref().unwrap_or_default(),
+                entry.group.as_deref().unwrap_or_default()
+            )),
+            None => {
+                keys.insert(&entry.key, entry);
+            }
+        }
+    }
+}
+
+/// 是否为带 scheme 的绝对 URI（如 `https://...`、`urn:...`），且不含空白字符
+fn is_absolute_uri(uri: &str) -> bool {
+    let Some((scheme, rest)) = uri.split_once(':') else {
+        return false;
+    };
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        && !rest.is_empty()
+        && !uri.chars().any(char::is_whitespace)
+}
+
+/// 检查回退链只引用已知的语言
+fn check_fallbacks(catalog: &ErrorCatalog, findings: &mut Findings) {
+    let languages = catalog.languages();
+    for (requested, chain) in &catalog.fallbacks {
+        for lang in chain {
+            if !languages.contains(&lang.as_str()) {
+                findings.problems.push(format!(
+                    "fallback chain for `{}` refers to `{}`, which is not a supported language",
+                    requested, lang
+                ));
+            }
+        }
+    }
+}
+
+/// 按 supported_languages 检查每个错误的翻译
+fn check_translations(catalog: &ErrorCatalog, findings: &mut Findings) {
+    let supported = &catalog.supported_languages;
+    if supported.is_empty() {
+        return;
+    }
+
+    let default_lang = &catalog.default_language;
+    if !supported.contains(default_lang) {
+        findings.problems.push(format!(
+            "default_language `{}` is not listed in supported_languages",
+            default_lang
+        ));
+    }
+
+    for entry in &catalog.errors {
+        for (lang, _) in &entry.messages {
+            if !supported.contains(lang) {
+                findings.problems.push(format!(
+                    "error `{}` has a message in `{}`, which is not listed in supported_languages",
+                    entry.key, lang
+                ));
+            }
+        }
+
+        for lang in supported {
+            if entry.message(lang).is_some() {
+                continue;
+            }
+            let description = format!("error `{}` is missing a `{}` translation", entry.key, lang);
+            if lang == default_lang {
+                // 默认语言是所有回退的终点，任何策略下都必须提供
+                findings.problems.push(description);
+                continue;
+            }
+            match catalog.missing_translation {
+                MissingTranslation::Error => findings.problems.push(description),
+                MissingTranslation::Warn => findings.warnings.push(description),
+                MissingTranslation::Fallback => {}
+            }
+        }
+    }
+}
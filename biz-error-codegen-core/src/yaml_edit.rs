@@ -0,0 +1,345 @@
+// 📦 YAML 文本改写 - 只改动需要改动的行，保留文件其余部分的顺序、注释和格式
+//
+// 按缩进定位 `errors.<key>`（或 `groups.<group>.errors.<key>`）。
+//
+// 写入 `message` 中的一种语言（导入翻译）：
+// - 块状映射：替换已有语言所在的行（包括多行字符串的续行），或在 `message` 块末尾追加一行
+// - 单行 flow 映射（`message: { en: ..., zh-CN: ... }`）：按已有消息重新生成这一行
+// - 没有 `message` 的错误（消息都来自 Fluent）：在错误末尾追加 `message` 块
+//
+// 新增错误（脚手架）：插入到指定错误之前或之后，或者追加到 `errors` 末尾。
+//
+// 改写后重新解析，确认改动都已按预期写入。
+
+use crate::Error;
+
+/// 写入一条消息
+#[cfg(feature = "translations")]
+pub(crate) struct MessageEdit {
+    /// 所属分组
+    pub group: Option<String>,
+    /// 错误名
+    pub key: String,
+    /// 语言
+    pub lang: String,
+    /// 新消息
+    pub message: String,
+    /// 配置文件中已有的消息（重新生成 flow 映射时使用）
+    pub messages: Vec<(String, String)>,
+}
+
+/// 依次写入所有消息，返回改写后的文件内容
+#[cfg(feature = "translations")]
+pub(crate) fn set_messages(content: &str, edits: &[MessageEdit]) -> Result<String, Error> {
+    let newline = if content.contains("\r\n") { "\r\n" } else { "\n" };
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    for edit in edits {
+        set_message(&mut lines, edit)?;
+    }
+
+    let mut output = lines.join(newline);
+    if content.ends_with('\n') {
+        output.push_str(newline);
+    }
+    verify_messages(&output, edits)?;
+    Ok(output)
+}
+
+#[cfg(feature = "translations")]
+fn set_message(lines: &mut Vec<String>, edit: &MessageEdit) -> Result<(), Error> {
+    let path: Vec<&str> = match edit.group {
+        Some(ref group) => vec!["groups", group, "errors", &edit.key],
+        None => vec!["errors", &edit.key],
+    };
+    let entry = find_key(lines, &path).ok_or_else(|| unsupported(None, &edit.key))?;
+    let entry_end = block_end(lines, entry);
+    let child_indent = child_indent(lines, entry, entry_end);
+    let line = format!(
+        "{}{}: {}",
+        " ".repeat(child_indent + 2),
+        edit.lang,
+        quote(&edit.message)
+    );
+
+    let message = (entry + 1..entry_end).find(|&i| {
+        mapping_key(&lines[i]).is_some_and(|(indent, key, _)| indent == child_indent && key == "message")
+    });
+    let Some(message) = message else {
+        lines.splice(
+            entry_end..entry_end,
+            [format!("{}message:", " ".repeat(child_indent)), line],
+        );
+        return Ok(());
+    };
+
+    let (_, _, value) = mapping_key(&lines[message]).unwrap_or_default();
+    if value.starts_with('{') {
+        if !value.ends_with('}') {
+            return Err(unsupported(Some(message), &edit.key));
+        }
+        let mut messages = edit.messages.clone();
+        match messages.iter_mut().find(|(lang, _)| *lang == edit.lang) {
+            Some((_, msg)) => *msg = edit.message.clone(),
+            None => messages.push((edit.lang.clone(), edit.message.clone())),
+        }
+        let pairs: Vec<_> = messages
+            .iter()
+            .map(|(lang, msg)| format!("{}: {}", lang, quote(msg)))
+            .collect();
+        lines[message] = format!(
+            "{}message: {{ {} }}",
+            " ".repeat(child_indent),
+            pairs.join(", ")
+        );
+        return Ok(());
+    }
+    if !(value.is_empty() || value.starts_with('#')) {
+        return Err(unsupported(Some(message), &edit.key));
+    }
+
+    let message_end = block_end(lines, message);
+    let lang_indent = child_indent_or(lines, message, message_end, child_indent + 2);
+    let line = format!("{}{}: {}", " ".repeat(lang_indent), edit.lang, quote(&edit.message));
+    let existing = (message + 1..message_end).find(|&i| {
+        mapping_key(&lines[i]).is_some_and(|(indent, key, _)| indent == lang_indent && key == edit.lang)
+    });
+    match existing {
+        Some(i) => {
+            let end = block_end(lines, i);
+            lines.splice(i..end, [line]);
+        }
+        None => lines.insert(message_end, line),
+    }
+    Ok(())
+}
+
+/// 新增错误的位置
+pub(crate) enum Position<'a> {
+    /// 插入到该错误之后
+    After(&'a str),
+    /// 插入到该错误之前（连同紧挨在它上方的注释）
+    Before(&'a str),
+    /// 追加到 `errors` 末尾
+    End,
+}
+
+/// 新增一个错误，返回改写后的文件内容
+///
+/// `body` 为错误名下的各行，按每层两个空格缩进，写入时换算为文件中使用的缩进。
+pub(crate) fn insert_error(
+    content: &str,
+    group: Option<&str>,
+    position: Position<'_>,
+    key: &str,
+    body: &[String],
+) -> Result<String, Error> {
+    let newline = if content.contains("\r\n") { "\r\n" } else { "\n" };
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    let errors: Vec<&str> = match group {
+        Some(group) => vec!["groups", group, "errors"],
+        None => vec!["errors"],
+    };
+    let not_found = |what: &str| Error::Parse {
+        path: None,
+        line: None,
+        column: None,
+        message: format!("cannot add error `{}`: {} not found in this file", key, what),
+    };
+
+    // (插入位置, 错误名的缩进, 每层缩进, 前后是否加空行)
+    let (at, indent, step, blank_before, blank_after) = match position {
+        Position::After(anchor) | Position::Before(anchor) => {
+            let path = [errors.as_slice(), &[anchor]].concat();
+            let entry = find_key(&lines, &path).ok_or_else(|| not_found(&format!("error `{}`", anchor)))?;
+            let indent = indent_of(&lines[entry]);
+            let end = block_end(&lines, entry);
+            let step = child_indent(&lines, entry, end) - indent;
+            if matches!(position, Position::After(_)) {
+                let blank = lines.get(end).is_some_and(|line| line.trim().is_empty());
+                (end, indent, step, blank, false)
+            } else {
+                let mut start = entry;
+                while start > 0 && lines[start - 1].trim_start().starts_with('#') {
+                    start -= 1;
+                }
+                let blank = start > 0 && lines[start - 1].trim().is_empty();
+                (start, indent, step, false, blank)
+            }
+        }
+        Position::End => match find_key(&lines, &errors) {
+            Some(parent) => {
+                let end = block_end(&lines, parent);
+                let indent = child_indent(&lines, parent, end);
+                (end, indent, indent - indent_of(&lines[parent]), false, false)
+            }
+            None if group.is_none() => {
+                lines.push("errors:".to_string());
+                (lines.len(), 2, 2, false, false)
+            }
+            None => return Err(not_found(&format!("group `{}`", group.unwrap_or_default()))),
+        },
+    };
+
+    let mut block = Vec::with_capacity(body.len() + 3);
+    if blank_before {
+        block.push(String::new());
+    }
+    block.push(format!("{}{}:", " ".repeat(indent), key));
+    for line in body {
+        let depth = indent_of(line) / 2 + 1;
+        block.push(format!("{}{}", " ".repeat(indent + depth * step), line.trim_start()));
+    }
+    if blank_after {
+        block.push(String::new());
+    }
+    lines.splice(at..at, block);
+
+    let mut output = lines.join(newline);
+    if content.ends_with('\n') || content.is_empty() {
+        output.push_str(newline);
+    }
+
+    let root: serde_yaml::Value =
+        serde_yaml::from_str(&output).map_err(|e| Error::from_yaml(None, e))?;
+    let inserted = errors
+        .iter()
+        .try_fold(&root, |value, key| value.get(key))
+        .and_then(|value| value.get(key))
+        .is_some_and(|value| value.is_mapping());
+    if !inserted {
+        return Err(not_found("a block-style `errors` mapping"));
+    }
+    Ok(output)
+}
+
+/// 按缩进查找路径对应的映射键所在的行
+fn find_key(lines: &[String], path: &[&str]) -> Option<usize> {
+    let mut stack: Vec<(usize, String)> = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let Some((indent, key, _)) = mapping_key(line) else {
+            continue;
+        };
+        while stack.last().is_some_and(|(last, _)| *last >= indent) {
+            stack.pop();
+        }
+        stack.push((indent, key));
+        if stack.len() == path.len() && stack.iter().zip(path).all(|((_, k), p)| k == p) {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// 第 `start` 行所在块的结束位置（之后第一个缩进不大于它的内容行；块末尾的空行和注释不计入）
+fn block_end(lines: &[String], start: usize) -> usize {
+    let indent = indent_of(&lines[start]);
+    let mut end = start + 1;
+    for (i, line) in lines.iter().enumerate().skip(start + 1) {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if indent_of(line) <= indent {
+            break;
+        }
+        end = i + 1;
+    }
+    end
+}
+
+/// 块中子项的缩进（块为空时为父级缩进 + 2）
+fn child_indent(lines: &[String], start: usize, end: usize) -> usize {
+    child_indent_or(lines, start, end, indent_of(&lines[start]) + 2)
+}
+
+fn child_indent_or(lines: &[String], start: usize, end: usize, default: usize) -> usize {
+    lines[start + 1..end]
+        .iter()
+        .find(|line| {
+            let trimmed = line.trim_start();
+            !trimmed.is_empty() && !trimmed.starts_with('#')
+        })
+        .map_or(default, |line| indent_of(line))
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// 解析映射键所在的行：(缩进, 键, 冒号之后的内容)
+fn mapping_key(line: &str) -> Option<(usize, String, &str)> {
+    let indent = indent_of(line);
+    let rest = &line[indent..];
+    if rest.is_empty() || rest.starts_with(['#', '-']) {
+        return None;
+    }
+
+    let (key, value) = match rest.chars().next() {
+        Some(quote @ ('"' | '\'')) => {
+            let end = rest[1..].find(quote)? + 1;
+            (rest[1..end].to_string(), rest[end + 1..].strip_prefix(':')?)
+        }
+        _ => {
+            let colon = match rest.find(": ") {
+                Some(pos) => pos,
+                None => rest.strip_suffix(':')?.len(),
+            };
+            (rest[..colon].trim_end().to_string(), &rest[colon + 1..])
+        }
+    };
+    Some((indent, key, value.trim()))
+}
+
+/// 双引号字符串
+pub(crate) fn quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(feature = "translations")]
+fn unsupported(line: Option<usize>, key: &str) -> Error {
+    Error::Parse {
+        path: None,
+        line: line.map(|i| i + 1),
+        column: None,
+        message: format!(
+            "cannot update the messages of error `{}` (only block mappings and single-line `message: {{ ... }}` are supported)",
+            key
+        ),
+    }
+}
+
+/// 重新解析改写后的内容，确认每条消息都已写入
+#[cfg(feature = "translations")]
+fn verify_messages(content: &str, edits: &[MessageEdit]) -> Result<(), Error> {
+    let root: serde_yaml::Value =
+        serde_yaml::from_str(content).map_err(|e| Error::from_yaml(None, e))?;
+    for edit in edits {
+        let errors = match edit.group {
+            Some(ref group) => root.get("groups").and_then(|g| g.get(group.as_str())),
+            None => Some(&root),
+        };
+        let written = errors
+            .and_then(|v| v.get("errors"))
+            .and_then(|v| v.get(edit.key.as_str()))
+            .and_then(|v| v.get("message"))
+            .and_then(|v| v.get(edit.lang.as_str()))
+            .and_then(|v| v.as_str());
+        if written != Some(edit.message.as_str()) {
+            return Err(unsupported(None, &edit.key));
+        }
+    }
+    Ok(())
+}
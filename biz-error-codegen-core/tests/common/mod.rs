@@ -0,0 +1,25 @@
+// 🧪 测试共用的夹具辅助函数
+
+// 每个测试文件只用到其中一部分
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+/// `tests/fixtures` 中的夹具
+pub fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+/// 将夹具中的文件复制到本测试独立的临时目录（先清除上次运行留下的目录），返回该目录
+///
+/// 文件在临时目录中保持相对于 `tests/fixtures` 的路径。
+pub fn copy_fixtures(test: &str, names: &[&str]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("biz-error-{}-{}", test, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    for name in names {
+        let target = dir.join(name);
+        std::fs::create_dir_all(target.parent().unwrap()).unwrap();
+        std::fs::copy(fixture(name), target).unwrap();
+    }
+    dir
+}
@@ -0,0 +1,149 @@
+// 🧪 derive 输入测试 - 枚举属性与 YAML 共用同一套校验
+
+use biz_error_codegen_core::ErrorCatalog;
+use syn::{DeriveInput, parse_quote};
+
+fn error(input: DeriveInput) -> String {
+    ErrorCatalog::from_derive_input(&input).unwrap_err().to_string()
+}
+
+#[test]
+fn attributes_build_catalog() {
+    let input: DeriveInput = parse_quote! {
+        #[biz(default_language = "zh-CN", supported_languages = ["en", "zh-CN"])]
+        #[biz(type_base_uri = "https://errors.example.com/")]
+        enum AppCode {
+            #[biz(code = 4000, http = 400, en = "INVALID PARAMETER", zh_CN = "参数无效")]
+            InvalidParam,
+            #[biz(code = 5000, en = "HTTP {status} error", zh_CN = "HTTP {status} 错误")]
+            HTTPError,
+        }
+    };
+    let catalog = ErrorCatalog::from_derive_input(&input).unwrap();
+
+    assert_eq!(catalog.default_language, "zh-CN");
+    assert_eq!(catalog.type_base_uri.as_deref(), Some("https://errors.example.com/"));
+
+    let invalid = &catalog.errors[0];
+    assert_eq!((invalid.key.as_str(), invalid.variant.as_str()), ("invalid_param", "InvalidParam"));
+    assert_eq!(invalid.http_status, 400);
+    assert_eq!(invalid.message("zh-CN"), Some("参数无效"));
+
+    let http = &catalog.errors[1];
+    assert_eq!((http.key.as_str(), http.variant.as_str()), ("http_error", "HTTPError"));
+    assert_eq!(http.http_status, 500);
+    assert_eq!(http.args[0].name, "status");
+}
+
+#[test]
+fn duplicate_codes_are_rejected() {
+    let message = error(parse_quote! {
+        enum AppCode {
+            #[biz(code = 4000, en = "A")]
+            First,
+            #[biz(code = 4000, en = "B")]
+            Second,
+        }
+    });
+    assert_eq!(
+        message,
+        "invalid error codes in `AppCode`\n  - duplicate code 4000: errors `first` and `second` both use it"
+    );
+}
+
+#[test]
+fn missing_translation_uses_yaml_rules() {
+    let message = error(parse_quote! {
+        #[biz(supported_languages = ["en", "zh-CN"])]
+        enum AppCode {
+            #[biz(code = 1, en = "A")]
+            A,
+        }
+    });
+    assert!(message.contains("missing a `zh-CN` translation"), "{}", message);
+}
+
+#[test]
+fn malformed_enums_are_rejected() {
+    assert_eq!(
+        error(parse_quote! {
+            enum AppCode {
+                #[biz(en = "A")]
+                A,
+            }
+        }),
+        "missing `#[biz(code = ...)]` on variant `A`"
+    );
+    assert_eq!(
+        error(parse_quote! {
+            enum AppCode {
+                #[biz(code = 1, en = "A")]
+                A(String),
+            }
+        }),
+        "BizErrorCode variants cannot have fields"
+    );
+    assert_eq!(
+        error(parse_quote! {
+            struct AppCode;
+        }),
+        "BizErrorCode can only be derived for enums"
+    );
+}
+
+#[test]
+fn negative_codes_are_accepted() {
+    let catalog = ErrorCatalog::from_derive_input(&parse_quote! {
+        enum AppCode {
+            #[biz(code = -1, en = "UNKNOWN")]
+            Unknown,
+            #[biz(code = -2147483648, en = "MIN")]
+            Min,
+        }
+    })
+    .unwrap();
+    assert_eq!(catalog.errors[0].code, -1);
+    assert_eq!(catalog.errors[1].code, i32::MIN);
+
+    assert_eq!(
+        error(parse_quote! {
+            enum AppCode {
+                #[biz(code = -2147483649, en = "A")]
+                A,
+            }
+        }),
+        "code does not fit in i32"
+    );
+    assert_eq!(
+        error(parse_quote! {
+            enum AppCode {
+                #[biz(code = "1", en = "A")]
+                A,
+            }
+        }),
+        "expected an integer code"
+    );
+}
+
+#[test]
+fn repeated_code_and_http_are_rejected() {
+    assert_eq!(
+        error(parse_quote! {
+            enum AppCode {
+                #[biz(code = 1, en = "A", code = 2)]
+                A,
+            }
+        }),
+        "duplicate `code`"
+    );
+    assert_eq!(
+        error(parse_quote! {
+            enum AppCode {
+                #[biz(code = 1, http = 400, en = "A")]
+                #[biz(http = 404)]
+                A,
+            }
+        }),
+        "duplicate `http`"
+    );
+}
@@ -0,0 +1,9 @@
+-service = Сервис
+
+files_remaining = { $count ->
+    [one] Остался { $count } файл
+    [few] Осталось { $count } файла
+   *[many] Осталось { $count } файлов
+}
+
+user_not_found = { -service }: пользователь { $user_id } не найден
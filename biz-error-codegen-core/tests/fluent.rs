@@ -0,0 +1,132 @@
+// 🧪 Fluent 资源测试 - .ftl 的读取、扁平化、参数推断和校验
+
+mod common;
+
+use biz_error_codegen_core::ErrorCatalog;
+use common::fixture;
+
+#[cfg(not(feature = "fluent"))]
+#[test]
+fn fluent_requires_feature() {
+    let err = ErrorCatalog::from_path(fixture("fluent/plural.yaml")).unwrap_err();
+    assert!(err.to_string().contains("`fluent` feature"), "{}", err);
+}
+
+#[cfg(feature = "fluent")]
+mod enabled {
+    use super::*;
+    use biz_error_codegen_core::Error;
+
+    fn problems(yaml: &str) -> Vec<String> {
+        match ErrorCatalog::from_yaml_str(yaml) {
+            Err(Error::Validation { problems, .. }) => problems,
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn fluent_messages_are_flattened_into_templates() {
+        let catalog = ErrorCatalog::from_path(fixture("fluent/plural.yaml")).unwrap();
+        let ftl = fixture("fluent/locales/ru.ftl");
+        assert_eq!(catalog.files, [fixture("fluent/plural.yaml"), ftl.clone()]);
+        assert_eq!(catalog.fluent[0].file.as_deref(), Some(ftl.as_path()));
+        assert_eq!(catalog.fluent[1].file, None);
+
+        let files = &catalog.errors[0];
+        assert_eq!(files.message("ru"), Some("Осталось {count} файлов"));
+        assert_eq!(files.message("en"), Some("{count} files left"));
+        assert_eq!(files.fluent, ["ru", "en"]);
+        // 默认语言来自 Fluent 时按引用的变量生成参数
+        assert_eq!(files.args[0].name, "count");
+
+        let user = &catalog.errors[1];
+        assert_eq!(user.message("ru"), Some("Сервис: пользователь {user_id} не найден"));
+        assert_eq!(user.fluent, ["ru"]);
+
+        let code = biz_error_codegen_core::render(&catalog).unwrap();
+        assert!(code.contains("fn fluent_resource(lang: &str)"), "{}", code);
+        assert!(code.contains("[few] Осталось { $count } файла"), "{}", code);
+    }
+
+    #[test]
+    fn fluent_messages_must_match_errors_and_arguments() {
+        let problems = problems(
+            r#"
+fluent:
+  en:
+    source: |
+      user_not_found = No user { $user_id } in { $tenant }
+      invalid_param = Invalid
+      unknown_error = Unknown
+      .hint = attribute only
+      orphan =
+          .title = no value
+errors:
+  user_not_found:
+    code: 4004
+    args: { user_id: u64 }
+    message:
+      zh-CN: "用户 {user_id} 不存在"
+  invalid_param:
+    code: 4000
+    message:
+      en: INVALID PARAMETER
+"#,
+        );
+        assert_eq!(
+            problems,
+            [
+                "Fluent message `user_not_found` in `en` resource uses `$tenant`, which is not an argument of the error (expected {user_id})",
+                "error `invalid_param` has both a `en` message and a Fluent message in `en` resource",
+                "Fluent message `unknown_error` in `en` resource does not match any error",
+                "Fluent message `orphan` in `en` resource does not match any error",
+            ]
+        );
+    }
+
+    #[test]
+    fn errors_without_any_message_are_rejected() {
+        let problems = problems(
+            "errors:
+  silent:
+    code: 4000
+",
+        );
+        assert_eq!(problems, ["error `silent` has no messages"]);
+    }
+
+    #[test]
+    fn fluent_syntax_errors_point_at_the_line() {
+        let err = ErrorCatalog::from_yaml_str(
+            r#"
+fluent:
+  en:
+    source: |
+      invalid_param = Invalid
+      broken = { $count ] }
+errors:
+  invalid_param:
+    code: 4000
+"#,
+        )
+        .unwrap_err();
+        match err {
+            Error::Parse { line, .. } => assert_eq!(line, Some(2)),
+            other => panic!("expected parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn missing_ftl_file_is_an_io_error() {
+        let err = ErrorCatalog::from_yaml_str(
+            "fluent:
+  en: does/not/exist.ftl
+errors:
+  invalid_param:
+    code: 4000
+",
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::Io { .. }), "{:?}", err);
+    }
+}
@@ -0,0 +1,87 @@
+// 🧪 配置文件格式测试 - YAML / JSON / TOML 共用同一套结构，生成完全相同的代码
+
+mod common;
+
+use std::path::Path;
+
+use biz_error_codegen_core::{ConfigFormat, Error, ErrorCatalog};
+#[cfg(any(feature = "json", feature = "toml"))]
+use biz_error_codegen_core::render;
+#[cfg(any(feature = "json", feature = "toml"))]
+use common::fixture;
+
+fn parse_error(content: &str, format: ConfigFormat) -> (Option<usize>, Option<usize>, String) {
+    match ErrorCatalog::from_str_as(content, format) {
+        Err(Error::Parse {
+            line,
+            column,
+            message,
+            ..
+        }) => (line, column, message),
+        other => panic!("expected parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn format_is_chosen_by_extension() {
+    assert_eq!(ConfigFormat::from_path(Path::new("a.yml")), ConfigFormat::Yaml);
+    assert_eq!(ConfigFormat::from_path(Path::new("a.JSON")), ConfigFormat::Json);
+    assert_eq!(ConfigFormat::from_path(Path::new("a.toml")), ConfigFormat::Toml);
+    // 其他扩展名按 YAML 解析
+    assert_eq!(ConfigFormat::from_path(Path::new("a.yaml.example")), ConfigFormat::Yaml);
+    assert_eq!(ConfigFormat::from_path(Path::new("biz_errors")), ConfigFormat::Yaml);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_generates_same_code_as_yaml() {
+    let yaml = ErrorCatalog::from_path(fixture("basic.yaml")).unwrap();
+    let json = ErrorCatalog::from_path(fixture("formats/basic.json")).unwrap();
+    assert_eq!(render(&json).unwrap(), render(&yaml).unwrap());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_errors_have_locations() {
+    let (line, column, message) = parse_error(
+        r#"{
+  "errors": {
+    "ok": { "code": 0, "mesage": { "en": "OK" } }
+  }
+}"#,
+        ConfigFormat::Json,
+    );
+    assert_eq!(line, Some(3));
+    assert!(column.is_some());
+    assert!(message.contains("unknown field `mesage`"), "{}", message);
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_generates_same_code_as_yaml() {
+    let yaml = ErrorCatalog::from_path(fixture("basic.yaml")).unwrap();
+    let toml = ErrorCatalog::from_path(fixture("formats/basic.toml")).unwrap();
+    assert_eq!(render(&toml).unwrap(), render(&yaml).unwrap());
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_errors_have_locations() {
+    let (line, column, message) = parse_error(
+        "[errors.ok]\ncode = 0\nhttp_stauts = 200\nmessage = { en = \"OK\" }\n",
+        ConfigFormat::Toml,
+    );
+    assert_eq!((line, column), (Some(3), Some(1)));
+    assert!(message.contains("unknown field `http_stauts`"), "{}", message);
+}
+
+#[cfg(not(feature = "toml"))]
+#[test]
+fn disabled_format_reports_missing_feature() {
+    let (_, _, message) = parse_error("", ConfigFormat::Toml);
+    assert_eq!(
+        message,
+        "Toml config files require the `toml` feature of biz-error-codegen-core \
+         (`codegen-toml` feature of biz-error)"
+    );
+}
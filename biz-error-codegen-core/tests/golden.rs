@@ -0,0 +1,79 @@
+// 🧪 Golden 测试 - build.rs 生成的代码与 golden 文件一致
+//
+// 每个 `tests/fixtures/<name>.yaml` 对应一个 `tests/golden/<name>.rs`，
+// 修改生成器后使用 `BIZ_ERROR_BLESS=1 cargo test` 重新生成 golden 文件。
+// 过程宏展开（`expand::expand_module`）的输出与 `generate` 的结果逐个 token 比较，
+// 行为一致性另由根 crate 的 `tests/golden.rs` 检查。
+
+use std::path::{Path, PathBuf};
+
+use biz_error_codegen_core::{ErrorCatalog, expand, generate, render};
+use quote::{ToTokens, quote};
+use syn::{Item, ItemMod};
+
+fn fixtures() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut paths: Vec<_> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "yaml"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn golden_path(fixture: &Path) -> PathBuf {
+    let name = fixture.file_stem().unwrap();
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(name)
+        .with_extension("rs")
+}
+
+#[test]
+fn build_rs_output_matches_golden() {
+    let bless = std::env::var_os("BIZ_ERROR_BLESS").is_some();
+
+    for fixture in fixtures() {
+        let catalog = ErrorCatalog::from_path(&fixture).unwrap();
+        let rendered = render(&catalog).unwrap();
+        let golden = golden_path(&fixture);
+
+        if bless {
+            std::fs::write(&golden, &rendered).unwrap();
+            continue;
+        }
+
+        let expected = std::fs::read_to_string(&golden)
+            .unwrap_or_else(|e| panic!("missing golden file {:?}: {}", golden, e));
+        assert_eq!(rendered, expected, "golden mismatch for {:?}", fixture);
+    }
+}
+
+/// 跟踪配置文件的 `const _: &[u8] = include_bytes!(...)` 常量，只存在于过程宏展开中
+fn is_tracking_const(item: &Item) -> bool {
+    matches!(item, Item::Const(c) if c.to_token_stream().to_string().contains("include_bytes"))
+}
+
+fn tokens(items: &[Item]) -> String {
+    items.iter().map(|item| item.to_token_stream().to_string()).collect::<Vec<_>>().join("\n")
+}
+
+#[test]
+fn macro_expansion_emits_generated_code() {
+    for fixture in fixtures() {
+        let catalog = ErrorCatalog::from_path(&fixture).unwrap();
+        let path = fixture.to_str().unwrap();
+
+        let expanded = expand::expand_module(quote!(#path), quote!(pub mod error_codes {}));
+        let module: ItemMod = syn::parse2(expanded)
+            .unwrap_or_else(|e| panic!("invalid expansion for {:?}: {}", fixture, e));
+        let (_, items) = module.content.unwrap();
+        let (tracked, items): (Vec<_>, Vec<_>) = items.into_iter().partition(is_tracking_const);
+
+        let generated = syn::parse2::<syn::File>(generate(&catalog)).unwrap().items;
+        assert_eq!(tracked.len(), catalog.files.len(), "tracked files for {:?}", fixture);
+        // golden 文件即 `render(generate(..))` 的输出（见上），两条路径因此得到相同的代码
+        assert_eq!(tokens(&items), tokens(&generated), "macro output differs for {:?}", fixture);
+    }
+}
@@ -0,0 +1,92 @@
+// 🔥 此文件由 biz-error 自动生成，请勿手动编辑！
+// 💡 如需修改错误码，请编辑 biz_errors.yaml 文件后重新编译
+
+use ::biz_error::ErrorCode as ErrorCodeTrait;
+/// 自动生成的业务错误码枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    ///SUCCESS
+    Success,
+    ///INVALID PARAMETER
+    InvalidParam,
+    ///USER NOT FOUND
+    UserNotFound,
+    ///INTERNAL "SERVER" ERROR
+    InternalError,
+}
+impl ErrorCodeTrait for ErrorCode {
+    fn code(&self) -> i32 {
+        match self {
+            ErrorCode::Success => 0i32,
+            ErrorCode::InvalidParam => 4000i32,
+            ErrorCode::UserNotFound => 4004i32,
+            ErrorCode::InternalError => 5000i32,
+        }
+    }
+    fn key(&self) -> &'static str {
+        match self {
+            ErrorCode::Success => "success",
+            ErrorCode::InvalidParam => "invalid_param",
+            ErrorCode::UserNotFound => "user_not_found",
+            ErrorCode::InternalError => "internal_error",
+        }
+    }
+    fn message(&self) -> &'static str {
+        self.message_lang("en")
+    }
+    fn message_lang(&self, lang: &str) -> &'static str {
+        match (self, self.resolve_lang(lang)) {
+            (ErrorCode::Success, "en") => "SUCCESS",
+            (ErrorCode::Success, "zh-CN") => "成功",
+            (ErrorCode::Success, _) => "SUCCESS",
+            (ErrorCode::InvalidParam, "en") => "INVALID PARAMETER",
+            (ErrorCode::InvalidParam, "zh-CN") => "参数无效",
+            (ErrorCode::InvalidParam, _) => "INVALID PARAMETER",
+            (ErrorCode::UserNotFound, "en") => "USER NOT FOUND",
+            (ErrorCode::UserNotFound, "zh-CN") => "用户不存在",
+            (ErrorCode::UserNotFound, _) => "USER NOT FOUND",
+            (ErrorCode::InternalError, "en") => "INTERNAL \"SERVER\" ERROR",
+            (ErrorCode::InternalError, "zh-CN") => "服务器内部错误",
+            (ErrorCode::InternalError, _) => "INTERNAL \"SERVER\" ERROR",
+        }
+    }
+    fn resolve_lang(&self, lang: &str) -> &'static str {
+        let available: &[&'static str] = match self {
+            ErrorCode::Success => &["en", "zh-CN"],
+            ErrorCode::InvalidParam => &["en", "zh-CN"],
+            ErrorCode::UserNotFound => &["en", "zh-CN"],
+            ErrorCode::InternalError => &["en", "zh-CN"],
+        };
+        ::biz_error::lang::resolve(lang, available, Self::fallbacks()).unwrap_or("en")
+    }
+    fn supported_languages() -> &'static [&'static str] {
+        &["en", "zh-CN"]
+    }
+    fn default_language() -> &'static str {
+        "en"
+    }
+    fn fallbacks() -> ::biz_error::lang::Fallbacks {
+        &[]
+    }
+    fn http_status_code(&self) -> u16 {
+        match self {
+            ErrorCode::Success => 200u16,
+            ErrorCode::InvalidParam => 400u16,
+            ErrorCode::UserNotFound => 404u16,
+            ErrorCode::InternalError => 500u16,
+        }
+    }
+}
+impl ::std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.message())
+    }
+}
+impl ::std::error::Error for ErrorCode {}
+/// 所有错误码常量列表（用于反射或遍历）
+pub const ALL_ERROR_CODES: &[ErrorCode] = &[
+    ErrorCode::Success,
+    ErrorCode::InvalidParam,
+    ErrorCode::UserNotFound,
+    ErrorCode::InternalError,
+];
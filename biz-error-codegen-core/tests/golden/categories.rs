@@ -0,0 +1,133 @@
+// 🔥 此文件由 biz-error 自动生成，请勿手动编辑！
+// 💡 如需修改错误码，请编辑 biz_errors.yaml 文件后重新编译
+
+use ::biz_error::ErrorCode as ErrorCodeTrait;
+/// 自动生成的业务错误码枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    ///NOT LOGIN
+    NotLogin,
+    ///AUTHORITY FAILED
+    AuthorityFailed,
+    ///INVALID PARAMETER
+    InvalidParam,
+    ///INTERNAL SERVER ERROR
+    InternalError,
+}
+impl ErrorCodeTrait for ErrorCode {
+    fn code(&self) -> i32 {
+        match self {
+            ErrorCode::NotLogin => 1000i32,
+            ErrorCode::AuthorityFailed => 1001i32,
+            ErrorCode::InvalidParam => 4000i32,
+            ErrorCode::InternalError => 5000i32,
+        }
+    }
+    fn key(&self) -> &'static str {
+        match self {
+            ErrorCode::NotLogin => "not_login",
+            ErrorCode::AuthorityFailed => "authority_failed",
+            ErrorCode::InvalidParam => "invalid_param",
+            ErrorCode::InternalError => "internal_error",
+        }
+    }
+    fn message(&self) -> &'static str {
+        self.message_lang("en")
+    }
+    fn message_lang(&self, lang: &str) -> &'static str {
+        match (self, self.resolve_lang(lang)) {
+            (ErrorCode::NotLogin, "en") => "NOT LOGIN",
+            (ErrorCode::NotLogin, _) => "NOT LOGIN",
+            (ErrorCode::AuthorityFailed, "en") => "AUTHORITY FAILED",
+            (ErrorCode::AuthorityFailed, _) => "AUTHORITY FAILED",
+            (ErrorCode::InvalidParam, "en") => "INVALID PARAMETER",
+            (ErrorCode::InvalidParam, _) => "INVALID PARAMETER",
+            (ErrorCode::InternalError, "en") => "INTERNAL SERVER ERROR",
+            (ErrorCode::InternalError, _) => "INTERNAL SERVER ERROR",
+        }
+    }
+    fn resolve_lang(&self, lang: &str) -> &'static str {
+        let available: &[&'static str] = match self {
+            ErrorCode::NotLogin => &["en"],
+            ErrorCode::AuthorityFailed => &["en"],
+            ErrorCode::InvalidParam => &["en"],
+            ErrorCode::InternalError => &["en"],
+        };
+        ::biz_error::lang::resolve(lang, available, Self::fallbacks()).unwrap_or("en")
+    }
+    fn supported_languages() -> &'static [&'static str] {
+        &["en"]
+    }
+    fn default_language() -> &'static str {
+        "en"
+    }
+    fn fallbacks() -> ::biz_error::lang::Fallbacks {
+        &[]
+    }
+    fn http_status_code(&self) -> u16 {
+        match self {
+            ErrorCode::NotLogin => 401u16,
+            ErrorCode::AuthorityFailed => 403u16,
+            ErrorCode::InvalidParam => 400u16,
+            ErrorCode::InternalError => 500u16,
+        }
+    }
+}
+impl ::std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.message())
+    }
+}
+impl ::std::error::Error for ErrorCode {}
+/// 所有错误码常量列表（用于反射或遍历）
+pub const ALL_ERROR_CODES: &[ErrorCode] = &[
+    ErrorCode::NotLogin,
+    ErrorCode::AuthorityFailed,
+    ErrorCode::InvalidParam,
+    ErrorCode::InternalError,
+];
+/// 错误码分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    ///认证/授权错误
+    Auth,
+    ///param
+    Param,
+    ///server
+    Server,
+}
+impl Category {
+    /// 配置中的分类名
+    pub fn name(&self) -> &'static str {
+        match self {
+            Category::Auth => "auth",
+            Category::Param => "param",
+            Category::Server => "server",
+        }
+    }
+    /// 分类的错误码范围
+    pub fn range(&self) -> ::std::ops::RangeInclusive<i32> {
+        match self {
+            Category::Auth => 1000i32..=1999i32,
+            Category::Param => 4000i32..=4099i32,
+            Category::Server => 5000i32..=5999i32,
+        }
+    }
+}
+/// 所有分类常量列表
+pub const ALL_CATEGORIES: &[Category] = &[
+    Category::Auth,
+    Category::Param,
+    Category::Server,
+];
+impl ErrorCode {
+    /// 获取错误码所属分类
+    pub fn category(&self) -> Category {
+        match self {
+            ErrorCode::NotLogin => Category::Auth,
+            ErrorCode::AuthorityFailed => Category::Auth,
+            ErrorCode::InvalidParam => Category::Param,
+            ErrorCode::InternalError => Category::Server,
+        }
+    }
+}
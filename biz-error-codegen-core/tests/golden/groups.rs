@@ -0,0 +1,283 @@
+// 🔥 此文件由 biz-error 自动生成，请勿手动编辑！
+// 💡 如需修改错误码，请编辑 biz_errors.yaml 文件后重新编译
+
+use ::biz_error::ErrorCode as ErrorCodeTrait;
+///用户相关错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UserError {
+    ///User {user_id} not found
+    UserNotFound,
+    ///INVALID PASSWORD
+    InvalidPassword,
+}
+impl ErrorCodeTrait for UserError {
+    fn code(&self) -> i32 {
+        match self {
+            UserError::UserNotFound => 4004i32,
+            UserError::InvalidPassword => 4010i32,
+        }
+    }
+    fn key(&self) -> &'static str {
+        match self {
+            UserError::UserNotFound => "user_not_found",
+            UserError::InvalidPassword => "invalid_password",
+        }
+    }
+    fn message(&self) -> &'static str {
+        self.message_lang("en")
+    }
+    fn message_lang(&self, lang: &str) -> &'static str {
+        match (self, self.resolve_lang(lang)) {
+            (UserError::UserNotFound, "en") => "User {user_id} not found",
+            (UserError::UserNotFound, "zh-CN") => "用户 {user_id} 不存在",
+            (UserError::UserNotFound, _) => "User {user_id} not found",
+            (UserError::InvalidPassword, "en") => "INVALID PASSWORD",
+            (UserError::InvalidPassword, "zh-CN") => "密码错误",
+            (UserError::InvalidPassword, _) => "INVALID PASSWORD",
+        }
+    }
+    fn resolve_lang(&self, lang: &str) -> &'static str {
+        let available: &[&'static str] = match self {
+            UserError::UserNotFound => &["en", "zh-CN"],
+            UserError::InvalidPassword => &["en", "zh-CN"],
+        };
+        ::biz_error::lang::resolve(lang, available, Self::fallbacks()).unwrap_or("en")
+    }
+    fn supported_languages() -> &'static [&'static str] {
+        &["en", "zh-CN"]
+    }
+    fn default_language() -> &'static str {
+        "en"
+    }
+    fn fallbacks() -> ::biz_error::lang::Fallbacks {
+        &[]
+    }
+    fn http_status_code(&self) -> u16 {
+        match self {
+            UserError::UserNotFound => 404u16,
+            UserError::InvalidPassword => 400u16,
+        }
+    }
+}
+impl ::std::fmt::Display for UserError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.message())
+    }
+}
+impl ::std::error::Error for UserError {}
+impl UserError {
+    /// 该分组的所有错误码常量列表
+    pub const ALL_ERROR_CODES: &'static [UserError] = &[
+        UserError::UserNotFound,
+        UserError::InvalidPassword,
+    ];
+}
+impl UserError {
+    /// 构造 `UserNotFound` 错误：User {user_id} not found
+    pub fn user_not_found(
+        user_id: impl ::std::fmt::Display,
+    ) -> ::biz_error::AppError<UserError> {
+        ::biz_error::AppError::new(UserError::UserNotFound).with_arg("user_id", user_id)
+    }
+}
+impl UserError {
+    /// 获取错误码所属分类
+    pub fn category(&self) -> Category {
+        match self {
+            UserError::UserNotFound => Category::Client,
+            UserError::InvalidPassword => Category::Client,
+        }
+    }
+}
+///order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderError {
+    ///ORDER NOT FOUND
+    NotFound,
+    ///PAYMENT FAILED
+    PaymentFailed,
+}
+impl ErrorCodeTrait for OrderError {
+    fn code(&self) -> i32 {
+        match self {
+            OrderError::NotFound => 4104i32,
+            OrderError::PaymentFailed => 5100i32,
+        }
+    }
+    fn key(&self) -> &'static str {
+        match self {
+            OrderError::NotFound => "not_found",
+            OrderError::PaymentFailed => "payment_failed",
+        }
+    }
+    fn message(&self) -> &'static str {
+        self.message_lang("en")
+    }
+    fn message_lang(&self, lang: &str) -> &'static str {
+        match (self, self.resolve_lang(lang)) {
+            (OrderError::NotFound, "en") => "ORDER NOT FOUND",
+            (OrderError::NotFound, "zh-CN") => "订单不存在",
+            (OrderError::NotFound, _) => "ORDER NOT FOUND",
+            (OrderError::PaymentFailed, "en") => "PAYMENT FAILED",
+            (OrderError::PaymentFailed, "zh-CN") => "支付失败",
+            (OrderError::PaymentFailed, _) => "PAYMENT FAILED",
+        }
+    }
+    fn resolve_lang(&self, lang: &str) -> &'static str {
+        let available: &[&'static str] = match self {
+            OrderError::NotFound => &["en", "zh-CN"],
+            OrderError::PaymentFailed => &["en", "zh-CN"],
+        };
+        ::biz_error::lang::resolve(lang, available, Self::fallbacks()).unwrap_or("en")
+    }
+    fn supported_languages() -> &'static [&'static str] {
+        &["en", "zh-CN"]
+    }
+    fn default_language() -> &'static str {
+        "en"
+    }
+    fn fallbacks() -> ::biz_error::lang::Fallbacks {
+        &[]
+    }
+    fn http_status_code(&self) -> u16 {
+        match self {
+            OrderError::NotFound => 404u16,
+            OrderError::PaymentFailed => 500u16,
+        }
+    }
+}
+impl ::std::fmt::Display for OrderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.message())
+    }
+}
+impl ::std::error::Error for OrderError {}
+impl OrderError {
+    /// 该分组的所有错误码常量列表
+    pub const ALL_ERROR_CODES: &'static [OrderError] = &[
+        OrderError::NotFound,
+        OrderError::PaymentFailed,
+    ];
+}
+impl OrderError {
+    /// 获取错误码所属分类
+    pub fn category(&self) -> Category {
+        match self {
+            OrderError::NotFound => Category::Client,
+            OrderError::PaymentFailed => Category::Server,
+        }
+    }
+}
+/// 自动生成的业务错误码枚举（汇总所有分组）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    ///用户相关错误
+    User(UserError),
+    ///order
+    Order(OrderError),
+}
+impl ::std::convert::From<UserError> for ErrorCode {
+    fn from(code: UserError) -> Self {
+        ErrorCode::User(code)
+    }
+}
+impl ::std::convert::From<OrderError> for ErrorCode {
+    fn from(code: OrderError) -> Self {
+        ErrorCode::Order(code)
+    }
+}
+impl ErrorCodeTrait for ErrorCode {
+    fn code(&self) -> i32 {
+        match self {
+            ErrorCode::User(code) => code.code(),
+            ErrorCode::Order(code) => code.code(),
+        }
+    }
+    fn key(&self) -> &'static str {
+        match self {
+            ErrorCode::User(code) => code.key(),
+            ErrorCode::Order(code) => code.key(),
+        }
+    }
+    fn message(&self) -> &'static str {
+        match self {
+            ErrorCode::User(code) => code.message(),
+            ErrorCode::Order(code) => code.message(),
+        }
+    }
+    fn message_lang(&self, lang: &str) -> &'static str {
+        match self {
+            ErrorCode::User(code) => code.message_lang(lang),
+            ErrorCode::Order(code) => code.message_lang(lang),
+        }
+    }
+    fn resolve_lang(&self, lang: &str) -> &'static str {
+        match self {
+            ErrorCode::User(code) => code.resolve_lang(lang),
+            ErrorCode::Order(code) => code.resolve_lang(lang),
+        }
+    }
+    fn supported_languages() -> &'static [&'static str] {
+        &["en", "zh-CN"]
+    }
+    fn default_language() -> &'static str {
+        "en"
+    }
+    fn fallbacks() -> ::biz_error::lang::Fallbacks {
+        &[]
+    }
+    fn http_status_code(&self) -> u16 {
+        match self {
+            ErrorCode::User(code) => code.http_status_code(),
+            ErrorCode::Order(code) => code.http_status_code(),
+        }
+    }
+}
+impl ::std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.message())
+    }
+}
+impl ::std::error::Error for ErrorCode {}
+/// 所有错误码常量列表（用于反射或遍历）
+pub const ALL_ERROR_CODES: &[ErrorCode] = &[
+    ErrorCode::User(UserError::UserNotFound),
+    ErrorCode::User(UserError::InvalidPassword),
+    ErrorCode::Order(OrderError::NotFound),
+    ErrorCode::Order(OrderError::PaymentFailed),
+];
+/// 错误码分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    ///client
+    Client,
+    ///server
+    Server,
+}
+impl Category {
+    /// 配置中的分类名
+    pub fn name(&self) -> &'static str {
+        match self {
+            Category::Client => "client",
+            Category::Server => "server",
+        }
+    }
+    /// 分类的错误码范围
+    pub fn range(&self) -> ::std::ops::RangeInclusive<i32> {
+        match self {
+            Category::Client => 4000i32..=4999i32,
+            Category::Server => 5000i32..=5999i32,
+        }
+    }
+}
+/// 所有分类常量列表
+pub const ALL_CATEGORIES: &[Category] = &[Category::Client, Category::Server];
+impl ErrorCode {
+    /// 获取错误码所属分类
+    pub fn category(&self) -> Category {
+        match self {
+            ErrorCode::User(code) => code.category(),
+            ErrorCode::Order(code) => code.category(),
+        }
+    }
+}
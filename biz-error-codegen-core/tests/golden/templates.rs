@@ -0,0 +1,99 @@
+// 🔥 此文件由 biz-error 自动生成，请勿手动编辑！
+// 💡 如需修改错误码，请编辑 biz_errors.yaml 文件后重新编译
+
+use ::biz_error::ErrorCode as ErrorCodeTrait;
+/// 自动生成的业务错误码枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    ///User {user_id} not found
+    UserNotFound,
+    ///Quota exceeded: {used}/{limit}
+    QuotaExceeded,
+    ///Expected {{json}}
+    LiteralBraces,
+}
+impl ErrorCodeTrait for ErrorCode {
+    fn code(&self) -> i32 {
+        match self {
+            ErrorCode::UserNotFound => 4004i32,
+            ErrorCode::QuotaExceeded => 4290i32,
+            ErrorCode::LiteralBraces => 4000i32,
+        }
+    }
+    fn key(&self) -> &'static str {
+        match self {
+            ErrorCode::UserNotFound => "user_not_found",
+            ErrorCode::QuotaExceeded => "quota_exceeded",
+            ErrorCode::LiteralBraces => "literal_braces",
+        }
+    }
+    fn message(&self) -> &'static str {
+        self.message_lang("en")
+    }
+    fn message_lang(&self, lang: &str) -> &'static str {
+        match (self, self.resolve_lang(lang)) {
+            (ErrorCode::UserNotFound, "en") => "User {user_id} not found",
+            (ErrorCode::UserNotFound, "zh-CN") => "用户 {user_id} 不存在",
+            (ErrorCode::UserNotFound, _) => "User {user_id} not found",
+            (ErrorCode::QuotaExceeded, "en") => "Quota exceeded: {used}/{limit}",
+            (ErrorCode::QuotaExceeded, "zh-CN") => "超出配额：{used}/{limit}",
+            (ErrorCode::QuotaExceeded, _) => "Quota exceeded: {used}/{limit}",
+            (ErrorCode::LiteralBraces, "en") => "Expected {{json}}",
+            (ErrorCode::LiteralBraces, "zh-CN") => "需要 {{json}}",
+            (ErrorCode::LiteralBraces, _) => "Expected {{json}}",
+        }
+    }
+    fn resolve_lang(&self, lang: &str) -> &'static str {
+        let available: &[&'static str] = match self {
+            ErrorCode::UserNotFound => &["en", "zh-CN"],
+            ErrorCode::QuotaExceeded => &["en", "zh-CN"],
+            ErrorCode::LiteralBraces => &["en", "zh-CN"],
+        };
+        ::biz_error::lang::resolve(lang, available, Self::fallbacks()).unwrap_or("en")
+    }
+    fn supported_languages() -> &'static [&'static str] {
+        &["en", "zh-CN"]
+    }
+    fn default_language() -> &'static str {
+        "en"
+    }
+    fn fallbacks() -> ::biz_error::lang::Fallbacks {
+        &[("zh-HK", &["zh-CN"]), ("ja", &["zh-CN", "en"])]
+    }
+    fn type_base_uri() -> ::std::option::Option<&'static str> {
+        ::std::option::Option::Some("https://errors.example.com/")
+    }
+    fn http_status_code(&self) -> u16 {
+        match self {
+            ErrorCode::UserNotFound => 404u16,
+            ErrorCode::QuotaExceeded => 429u16,
+            ErrorCode::LiteralBraces => 400u16,
+        }
+    }
+}
+impl ::std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.message())
+    }
+}
+impl ::std::error::Error for ErrorCode {}
+/// 所有错误码常量列表（用于反射或遍历）
+pub const ALL_ERROR_CODES: &[ErrorCode] = &[
+    ErrorCode::UserNotFound,
+    ErrorCode::QuotaExceeded,
+    ErrorCode::LiteralBraces,
+];
+impl ErrorCode {
+    /// 构造 `UserNotFound` 错误：User {user_id} not found
+    pub fn user_not_found(
+        user_id: impl ::std::fmt::Display,
+    ) -> ::biz_error::AppError<ErrorCode> {
+        ::biz_error::AppError::new(ErrorCode::UserNotFound).with_arg("user_id", user_id)
+    }
+    /// 构造 `QuotaExceeded` 错误：Quota exceeded: {used}/{limit}
+    pub fn quota_exceeded(used: u32, limit: u32) -> ::biz_error::AppError<ErrorCode> {
+        ::biz_error::AppError::new(ErrorCode::QuotaExceeded)
+            .with_arg("used", used)
+            .with_arg("limit", limit)
+    }
+}
@@ -0,0 +1,100 @@
+// 🧪 多文件配置测试 - include、目录和 glob 模式合并为一个错误码目录
+
+mod common;
+
+use std::path::PathBuf;
+
+use biz_error_codegen_core::{Error, ErrorCatalog, render};
+use common::fixture;
+
+fn problems(path: PathBuf) -> Vec<String> {
+    match ErrorCatalog::from_path(path) {
+        Err(Error::Validation { problems, .. }) => problems,
+        other => panic!("expected validation error, got {:?}", other),
+    }
+}
+
+fn keys(catalog: &ErrorCatalog) -> Vec<&str> {
+    catalog.errors.iter().map(|e| e.key.as_str()).collect()
+}
+
+#[test]
+fn include_generates_same_code_as_single_file() {
+    let single = ErrorCatalog::from_path(fixture("basic.yaml")).unwrap();
+    let merged = ErrorCatalog::from_path(fixture("include/root.yaml")).unwrap();
+    assert_eq!(render(&merged).unwrap(), render(&single).unwrap());
+
+    assert_eq!(
+        merged.files,
+        [
+            fixture("include/root.yaml"),
+            fixture("include/errors/auth.yaml"),
+            fixture("include/errors/system.yaml"),
+        ]
+    );
+    assert_eq!(
+        merged.errors[1].file.as_deref(),
+        Some(fixture("include/errors/auth.yaml").as_path())
+    );
+    // 单文件配置不记录错误所在的文件
+    assert!(single.errors.iter().all(|e| e.file.is_none()));
+}
+
+#[test]
+fn glob_and_directory_paths_are_merged_in_file_name_order() {
+    let from_glob = ErrorCatalog::from_path(fixture("include/errors/*.yaml")).unwrap();
+    assert_eq!(keys(&from_glob), ["invalid_param", "user_not_found", "internal_error"]);
+
+    let from_dir = ErrorCatalog::from_path(fixture("include/errors")).unwrap();
+    assert_eq!(keys(&from_dir), keys(&from_glob));
+    assert_eq!(from_dir.files, from_glob.files);
+}
+
+#[test]
+fn empty_glob_is_an_error() {
+    let err = ErrorCatalog::from_path(fixture("include/errors/*.json")).unwrap_err();
+    assert!(matches!(err, Error::Io { .. }), "{:?}", err);
+}
+
+#[test]
+fn duplicate_codes_across_files_name_both_files() {
+    let problems = problems(fixture("include/duplicate_code.yaml"));
+    let auth = fixture("include/codes/auth.yaml");
+    let order = fixture("include/codes/order.yaml");
+    assert_eq!(
+        problems,
+        [format!(
+            "duplicate code 4000: errors `invalid_param` ({}) and `order_not_found` ({}) both use it",
+            auth.display(),
+            order.display()
+        )]
+    );
+}
+
+#[test]
+fn conflicting_definitions_across_files_are_rejected() {
+    let problems = problems(fixture("include/conflict"));
+    let auth = fixture("include/conflict/auth.yaml");
+    assert_eq!(problems.len(), 2, "{:?}", problems);
+    assert!(
+        problems[0].starts_with(&format!(
+            "`default_language` is already set in {}",
+            auth.display()
+        )),
+        "{:?}",
+        problems
+    );
+    assert_eq!(
+        problems[1],
+        format!("error `invalid_param` is already defined in {}", auth.display())
+    );
+}
+
+#[test]
+fn include_cycles_are_rejected() {
+    let problems = problems(fixture("include/cycle/a.yaml"));
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].starts_with("include cycle: "), "{}", problems[0]);
+    assert!(problems[0].contains("a.yaml -> "), "{}", problems[0]);
+    assert!(problems[0].ends_with("a.yaml"), "{}", problems[0]);
+}
@@ -0,0 +1,160 @@
+// 🧪 脚手架测试 - 分配错误码、向配置文件中新增错误
+
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use biz_error_codegen_core::{Error, ErrorCatalog, NewError, add_error, next_code};
+use common::fixture;
+
+/// 将夹具复制到独立的临时目录
+fn copy_fixture(name: &str, test: &str) -> PathBuf {
+    common::copy_fixtures(&format!("scaffold-{}", test), &[name]).join(name)
+}
+
+fn new_error(key: &str, code: i32, messages: &[(&str, &str)]) -> NewError {
+    NewError {
+        key: key.to_string(),
+        code,
+        messages: messages
+            .iter()
+            .map(|(lang, msg)| (lang.to_string(), msg.to_string()))
+            .collect(),
+        ..NewError::default()
+    }
+}
+
+fn keys(path: &Path) -> Vec<(String, i32)> {
+    let catalog = ErrorCatalog::from_path(path).unwrap();
+    catalog.errors.into_iter().map(|e| (e.key, e.code)).collect()
+}
+
+#[test]
+fn next_code_is_the_first_free_code_in_the_range() {
+    let catalog = ErrorCatalog::from_path(fixture("categories.yaml")).unwrap();
+    assert_eq!(next_code(&catalog, "auth").unwrap(), 1002);
+    assert_eq!(next_code(&catalog, "param").unwrap(), 4001);
+
+    let err = next_code(&catalog, "billing").unwrap_err();
+    assert!(
+        err.to_string().contains("unknown category `billing` (expected one of: auth, param, server)"),
+        "{}",
+        err
+    );
+
+    let full = ErrorCatalog::from_yaml_str(
+        "categories:
+  tiny:
+    range: [1, 1]
+errors:
+  only:
+    code: 1
+    message: { en: Only }
+",
+    )
+    .unwrap();
+    let err = next_code(&full, "tiny").unwrap_err();
+    assert!(err.to_string().contains("no free code left in 1..=1"), "{}", err);
+}
+
+#[test]
+fn new_errors_are_inserted_next_to_their_range() {
+    let path = copy_fixture("categories.yaml", "insert");
+    let mut token_expired = new_error("token_expired", 1002, &[("en", "TOKEN EXPIRED")]);
+    token_expired.http_status = Some(401);
+    token_expired.description = Some("Access token is no longer valid".to_string());
+    assert_eq!(add_error(&path, &token_expired).unwrap(), path);
+    add_error(&path, &new_error("missing_field", 4050, &[("en", "MISSING \"FIELD\"")])).unwrap();
+    add_error(&path, &new_error("session_revoked", 1500, &[("en", "SESSION REVOKED")])).unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(
+        content.contains(
+            r#"      en: "AUTHORITY FAILED"
+  token_expired:
+    code: 1002
+    http_status: 401
+    description: "Access token is no longer valid"
+    message:
+      en: "TOKEN EXPIRED"
+  session_revoked:
+    code: 1500
+    message:
+      en: "SESSION REVOKED"
+  invalid_param:
+"#
+        ),
+        "{}",
+        content
+    );
+    assert!(
+        content.starts_with(
+            "default_language: en\ncategories:\n  auth:\n    range: [1000, 1999]\n    http_status: 401\n"
+        ),
+        "{}",
+        content
+    );
+    assert_eq!(
+        keys(&path),
+        [
+            ("not_login".to_string(), 1000),
+            ("authority_failed".to_string(), 1001),
+            ("token_expired".to_string(), 1002),
+            ("session_revoked".to_string(), 1500),
+            ("invalid_param".to_string(), 4000),
+            ("missing_field".to_string(), 4050),
+            ("internal_error".to_string(), 5000),
+        ]
+    );
+}
+
+#[test]
+fn new_errors_join_the_neighbouring_group() {
+    let path = copy_fixture("groups.yaml", "groups");
+    let messages = [("en", "ORDER CANCELLED"), ("zh-CN", "订单已取消")];
+    add_error(&path, &new_error("order_cancelled", 4105, &messages)).unwrap();
+
+    let mut refund = new_error("refund_failed", 4106, &[("en", "REFUND FAILED"), ("zh-CN", "退款失败")]);
+    refund.group = Some("order".to_string());
+    add_error(&path, &refund).unwrap();
+
+    // 分组中没有更小的错误码时插入到最小的错误之前
+    let mut expired = new_error("order_expired", 4100, &[("en", "ORDER EXPIRED"), ("zh-CN", "订单已过期")]);
+    expired.group = Some("order".to_string());
+    add_error(&path, &expired).unwrap();
+
+    let catalog = ErrorCatalog::from_path(&path).unwrap();
+    let order: Vec<_> = catalog
+        .errors
+        .iter()
+        .filter(|e| e.group.as_deref() == Some("order"))
+        .map(|e| e.key.as_str())
+        .collect();
+    assert_eq!(
+        order,
+        ["order_expired", "not_found", "order_cancelled", "refund_failed", "payment_failed"]
+    );
+}
+
+#[test]
+fn invalid_new_errors_leave_the_file_untouched() {
+    let path = copy_fixture("groups.yaml", "invalid");
+    let original = std::fs::read_to_string(&path).unwrap();
+
+    let duplicate = new_error("not_found", 4004, &[("en", "X"), ("zh-CN", "X")]);
+    match add_error(&path, &duplicate) {
+        Err(Error::Validation { problems, .. }) => assert_eq!(
+            problems,
+            [
+                "error `not_found` already exists (code 4104)",
+                "code 4004 is already used by `user_not_found`",
+            ]
+        ),
+        other => panic!("expected validation error, got {:?}", other),
+    }
+
+    // 缺少 zh-CN 翻译：写入后校验失败，恢复原文件
+    let err = add_error(&path, &new_error("user_locked", 4011, &[("en", "USER LOCKED")])).unwrap_err();
+    assert!(err.to_string().contains("missing a `zh-CN` translation"), "{}", err);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), original);
+}
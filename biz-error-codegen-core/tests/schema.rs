@@ -0,0 +1,73 @@
+// 🧪 配置格式校验测试
+
+use biz_error_codegen_core::{Error, ErrorCatalog};
+
+fn parse_error(yaml: &str) -> (usize, usize, String) {
+    match ErrorCatalog::from_yaml_str(yaml) {
+        Err(Error::Parse {
+            line: Some(line),
+            column: Some(column),
+            message,
+            ..
+        }) => (line, column, message),
+        other => panic!("expected parse error with location, got {:?}", other),
+    }
+}
+
+#[test]
+fn unknown_error_field_is_rejected() {
+    let (line, column, message) = parse_error(
+        "errors:
+  invalid_param:
+    code: 4000
+    http_stauts: 400
+    message:
+      en: INVALID PARAMETER
+",
+    );
+    assert_eq!((line, column), (4, 5));
+    assert!(message.contains("unknown field `http_stauts`"), "{}", message);
+}
+
+#[test]
+fn unknown_top_level_field_is_rejected() {
+    let (line, _, message) = parse_error(
+        "default_languag: en
+errors: {}
+",
+    );
+    assert_eq!(line, 1);
+    assert!(message.contains("unknown field `default_languag`"), "{}", message);
+}
+
+#[test]
+fn wrong_type_is_rejected() {
+    let (line, _, message) = parse_error(
+        "errors:
+  invalid_param:
+    code: \"4000\"
+    message:
+      en: INVALID PARAMETER
+",
+    );
+    assert_eq!(line, 3);
+    assert!(message.contains("invalid type"), "{}", message);
+}
+
+#[test]
+fn missing_field_is_rejected() {
+    let (_, _, message) = parse_error(
+        "errors:
+  invalid_param:
+    message:
+      en: INVALID PARAMETER
+",
+    );
+    assert!(message.contains("missing field `code`"), "{}", message);
+}
+
+#[test]
+fn error_display_includes_location() {
+    let err = ErrorCatalog::from_yaml_str("errors:\n  x:\n    mesage: {}\n").unwrap_err();
+    assert!(err.to_string().starts_with("<string>:3:5: "), "{}", err);
+}
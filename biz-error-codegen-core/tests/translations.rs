@@ -0,0 +1,408 @@
+// 🧪 翻译交换测试 - XLIFF 2.0 / PO 的导出、解析，以及导入回 YAML 配置
+#![cfg(feature = "translations")]
+
+mod common;
+
+use std::path::PathBuf;
+
+use biz_error_codegen_core::{
+    Error, ErrorCatalog, InvalidEntry, StaleEntry, TranslationUnit, Translations, export_po, export_xliff,
+    import_translations,
+};
+use common::fixture;
+
+/// 将 `translations` 夹具复制到独立的临时目录，返回其中的主配置文件
+fn copy_fixture(test: &str) -> PathBuf {
+    let files = ["translations/biz_errors.yaml", "translations/errors/user.yaml"];
+    common::copy_fixtures(&format!("translations-{}", test), &files).join(files[0])
+}
+
+fn unit(key: &str, source: &str, target: Option<&str>) -> TranslationUnit {
+    TranslationUnit {
+        key: key.to_string(),
+        source: source.to_string(),
+        target: target.map(String::from),
+    }
+}
+
+#[test]
+fn xliff_export_has_notes_and_round_trips() {
+    let catalog = ErrorCatalog::from_path(fixture("translations/biz_errors.yaml")).unwrap();
+    let xliff = export_xliff(&catalog, "zh-CN");
+    assert!(xliff.contains(r#"srcLang="en" trgLang="zh-CN""#), "{}", xliff);
+    assert!(xliff.contains(r#"<note category="code">4004</note>"#), "{}", xliff);
+    assert!(xliff.contains(r#"<note category="key">user_not_found</note>"#), "{}", xliff);
+    assert!(xliff.contains(r#"<note category="description">Request succeeded</note>"#), "{}", xliff);
+    assert!(xliff.contains(r#"<segment state="initial">"#), "{}", xliff);
+
+    let parsed = Translations::from_xliff(&xliff).unwrap();
+    assert_eq!(parsed.source_lang.as_deref(), Some("en"));
+    assert_eq!(parsed.lang, "zh-CN");
+    assert_eq!(
+        parsed.units,
+        [
+            unit("success", "SUCCESS", Some("成功")),
+            unit("invalid_param", "Invalid parameter {field}", Some("参数 {field} 无效")),
+            unit("user_not_found", "User {user_id}\nnot found", Some("用户 {user_id} 不存在")),
+            unit("user_locked", "User is locked", None),
+        ]
+    );
+}
+
+#[test]
+fn po_export_has_comments_and_round_trips() {
+    let catalog = ErrorCatalog::from_path(fixture("translations/biz_errors.yaml")).unwrap();
+    let po = export_po(&catalog, "ja");
+    assert!(po.contains("\"Language: ja\\n\""), "{}", po);
+    assert!(
+        po.contains(
+            "#. code: 4004\n#. key: user_not_found\n#. description: 按 ID 查找用户失败\n\
+             msgctxt \"user_not_found\"\nmsgid \"\"\n\"User {user_id}\\n\"\n\"not found\"\nmsgstr \"\"\n"
+        ),
+        "{}",
+        po
+    );
+
+    let parsed = Translations::from_po(&po).unwrap();
+    assert_eq!(parsed.source_lang.as_deref(), Some("en"));
+    assert_eq!(parsed.lang, "ja");
+    assert_eq!(parsed.units.len(), 4);
+    assert_eq!(parsed.units[2], unit("user_not_found", "User {user_id}\nnot found", None));
+}
+
+#[test]
+fn po_fuzzy_and_empty_entries_are_untranslated() {
+    let parsed = Translations::from_po(
+        r#"msgid ""
+msgstr "Language: zh-CN\n"
+
+#, fuzzy
+msgctxt "success"
+msgid "SUCCESS"
+msgstr "成功？"
+
+msgctxt "invalid_param"
+msgid "INVALID \"PARAMETER\""
+msgstr ""
+msgctxt "user_not_found"
+msgid "USER NOT FOUND"
+msgstr "用户"
+"不存在"
+"#,
+    )
+    .unwrap();
+    assert_eq!(parsed.source_lang, None);
+    assert_eq!(
+        parsed.units,
+        [
+            unit("success", "SUCCESS", None),
+            unit("invalid_param", "INVALID \"PARAMETER\"", None),
+            unit("user_not_found", "USER NOT FOUND", Some("用户不存在")),
+        ]
+    );
+}
+
+#[test]
+fn malformed_files_report_the_line() {
+    let err = Translations::from_po("msgid \"\"\nmsgstr \"Language: ja\\n\"\n\nmsgctxt \"a\"\nmsgid \"x\\q\"\n").unwrap_err();
+    match err {
+        Error::Parse { line, .. } => assert_eq!(line, Some(5)),
+        other => panic!("expected parse error, got {:?}", other),
+    }
+
+    let err = Translations::from_xliff("<xliff version=\"2.0\" srcLang=\"en\">\n</xliff>").unwrap_err();
+    assert!(err.to_string().contains("trgLang"), "{}", err);
+}
+
+#[test]
+fn import_rewrites_only_the_imported_messages() {
+    let config = copy_fixture("import");
+    let dir = config.parent().unwrap();
+    let translations = Translations {
+        source_lang: Some("en".to_string()),
+        lang: "ja".to_string(),
+        units: vec![
+            unit("success", "SUCCESS", Some("成功しました")),
+            unit("invalid_param", "Invalid parameter {field}", Some("パラメータ {field} が無効です")),
+            unit("user_not_found", "USER NOT FOUND", Some("ユーザーが見つかりません")),
+            unit("user_locked", "User is locked", Some("ユーザーは\"ロック\"されています")),
+            unit("removed_error", "Removed", Some("削除")),
+        ],
+    };
+
+    let report = import_translations(&config, &translations).unwrap();
+    assert_eq!(report.updated, ["success", "invalid_param", "user_locked"]);
+    assert_eq!(report.unchanged, Vec::<String>::new());
+    assert_eq!(report.untranslated, ["user_not_found"]);
+    assert_eq!(
+        report.stale,
+        [StaleEntry {
+            key: "user_not_found".to_string(),
+            exported: "USER NOT FOUND".to_string(),
+            current: "User {user_id}\nnot found".to_string(),
+        }]
+    );
+    assert_eq!(report.unknown, ["removed_error"]);
+    assert_eq!(report.files, [config.clone(), dir.join("errors/user.yaml")]);
+
+    let root = std::fs::read_to_string(&config).unwrap();
+    assert_eq!(
+        root,
+        r#"# 翻译导入测试：导入后只有对应语言的消息会被改写
+default_language: en
+supported_languages: [en, zh-CN, ja]
+# 翻译进行中，缺少的译文回退到默认语言
+missing_translation: warn
+include:
+  - errors/*.yaml
+errors:
+  # 成功响应
+  success:
+    code: 0
+    http_status: 200
+    description: Request succeeded
+    message:
+      en: "SUCCESS"
+      zh-CN: "成功" # 不要改动
+      ja: "成功しました"
+  invalid_param:
+    code: 4000
+    message: { en: "Invalid parameter {field}", zh-CN: "参数 {field} 无效", ja: "パラメータ {field} が無効です" }
+"#
+    );
+    let user = std::fs::read_to_string(dir.join("errors/user.yaml")).unwrap();
+    assert!(
+        user.ends_with(
+            "    message:\n      en: \"User is locked\"\n      ja: \"ユーザーは\\\"ロック\\\"されています\"\n"
+        ),
+        "{}",
+        user
+    );
+
+    let catalog = ErrorCatalog::from_path(&config).unwrap();
+    assert_eq!(catalog.errors[3].message("ja"), Some("ユーザーは\"ロック\"されています"));
+
+    // 再次导入相同的译文不改动任何文件
+    let report = import_translations(&config, &translations).unwrap();
+    assert_eq!(report.updated, Vec::<String>::new());
+    assert_eq!(report.unchanged, ["success", "invalid_param", "user_locked"]);
+    assert!(report.files.is_empty());
+}
+
+#[test]
+fn import_replaces_existing_translations() {
+    let config = copy_fixture("replace");
+    let catalog = ErrorCatalog::from_path(&config).unwrap();
+    let mut translations = Translations::from_xliff(&export_xliff(&catalog, "zh-CN")).unwrap();
+    translations.units[2].target = Some("找不到用户 {user_id}".to_string());
+
+    let report = import_translations(&config, &translations).unwrap();
+    assert_eq!(report.updated, ["user_not_found"]);
+    assert_eq!(report.untranslated, ["user_locked"]);
+
+    let user = std::fs::read_to_string(config.parent().unwrap().join("errors/user.yaml")).unwrap();
+    assert!(
+        user.contains(
+            "      en: |-\n        User {user_id}\n        not found\n      zh-CN: \"找不到用户 {user_id}\"\n"
+        ),
+        "{}",
+        user
+    );
+}
+
+#[test]
+fn import_skips_targets_with_wrong_placeholders() {
+    let config = copy_fixture("placeholders");
+    let dir = config.parent().unwrap();
+    let original = (
+        std::fs::read_to_string(&config).unwrap(),
+        std::fs::read_to_string(dir.join("errors/user.yaml")).unwrap(),
+    );
+    let translations = Translations {
+        source_lang: Some("en".to_string()),
+        lang: "ja".to_string(),
+        units: vec![
+            unit("invalid_param", "Invalid parameter {field}", Some("パラメータ {name} が無効です")),
+            unit("user_not_found", "User {user_id}\nnot found", Some("ユーザーが見つかりません")),
+            unit("user_locked", "User is locked", Some("ユーザー {user_id} はロックされています")),
+            unit("success", "SUCCESS", Some("成功 {")),
+        ],
+    };
+
+    let report = import_translations(&config, &translations).unwrap();
+    assert_eq!(
+        report.invalid,
+        [
+            InvalidEntry {
+                key: "invalid_param".to_string(),
+                target: "パラメータ {name} が無効です".to_string(),
+                problem: "uses placeholders {name}, expected {field}".to_string(),
+            },
+            InvalidEntry {
+                key: "user_not_found".to_string(),
+                target: "ユーザーが見つかりません".to_string(),
+                problem: "uses placeholders {}, expected {user_id}".to_string(),
+            },
+            InvalidEntry {
+                key: "user_locked".to_string(),
+                target: "ユーザー {user_id} はロックされています".to_string(),
+                problem: "uses placeholders {user_id}, expected {}".to_string(),
+            },
+            InvalidEntry {
+                key: "success".to_string(),
+                target: "成功 {".to_string(),
+                problem: "unclosed placeholder `{`".to_string(),
+            },
+        ]
+    );
+    assert!(report.updated.is_empty());
+    assert!(report.files.is_empty());
+    assert_eq!(report.untranslated, ["success", "invalid_param", "user_not_found", "user_locked"]);
+    assert_eq!(
+        (
+            std::fs::read_to_string(&config).unwrap(),
+            std::fs::read_to_string(dir.join("errors/user.yaml")).unwrap(),
+        ),
+        original
+    );
+}
+
+#[test]
+fn import_rejects_other_source_language() {
+    let config = copy_fixture("source");
+    let translations = Translations {
+        source_lang: Some("zh-CN".to_string()),
+        lang: "ja".to_string(),
+        units: vec![unit("success", "成功", Some("成功"))],
+    };
+    let err = import_translations(&config, &translations).unwrap_err();
+    assert!(err.to_string().contains("exported from `zh-CN`"), "{}", err);
+}
+
+#[test]
+fn import_rejects_undeclared_language() {
+    let config = copy_fixture("undeclared");
+    let original = std::fs::read_to_string(&config).unwrap();
+    let translations = Translations {
+        source_lang: Some("en".to_string()),
+        lang: "fr".to_string(),
+        units: vec![unit("success", "SUCCESS", Some("SUCCÈS"))],
+    };
+    let err = import_translations(&config, &translations).unwrap_err();
+    assert!(err.to_string().contains("`fr` is not listed in supported_languages"), "{}", err);
+    assert_eq!(std::fs::read_to_string(&config).unwrap(), original);
+}
+
+#[test]
+fn import_restores_every_file_when_the_result_is_invalid() {
+    let dir = std::env::temp_dir().join(format!("biz-error-translations-restore-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let config = dir.join("biz_errors.yaml");
+    let root = "supported_languages: [en, ja]
+include:
+  - more.yaml
+errors:
+  first:
+    code: 1
+    message:
+      en: First
+";
+    let more = "errors:
+  second:
+    code: 2
+    message:
+      en: Second
+  third:
+    code: 3
+    message:
+      en: Third
+";
+    std::fs::write(&config, root).unwrap();
+    std::fs::write(dir.join("more.yaml"), more).unwrap();
+
+    // 两个文件都会被改写，但 `third` 仍缺少 ja 翻译，校验失败
+    let translations = Translations {
+        source_lang: None,
+        lang: "ja".to_string(),
+        units: vec![unit("first", "First", Some("一")), unit("second", "Second", Some("二"))],
+    };
+    let err = import_translations(&config, &translations).unwrap_err();
+    assert!(err.to_string().contains("error `third` is missing a `ja` translation"), "{}", err);
+    assert_eq!(std::fs::read_to_string(&config).unwrap(), root);
+    assert_eq!(std::fs::read_to_string(dir.join("more.yaml")).unwrap(), more);
+
+    let translations = Translations {
+        units: vec![
+            unit("first", "First", Some("一")),
+            unit("second", "Second", Some("二")),
+            unit("third", "Third", Some("三")),
+        ],
+        ..translations
+    };
+    let report = import_translations(&config, &translations).unwrap();
+    assert_eq!(report.files, [config.clone(), dir.join("more.yaml")]);
+    assert_eq!(ErrorCatalog::from_path(&config).unwrap().errors[2].message("ja"), Some("三"));
+}
+
+#[test]
+fn import_into_grouped_errors() {
+    let dir = std::env::temp_dir().join(format!("biz-error-translations-groups-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let config = dir.join("biz_errors.yaml");
+    std::fs::write(
+        &config,
+        "supported_languages: [en, zh-CN]
+missing_translation: fallback
+groups:
+  user:
+    errors:
+      not_found:
+        code: 4004
+        message:
+          en: Not found
+  order:
+    errors:
+      not_found_order:
+        code: 4104
+        message:
+          en: Order not found
+
+          zh-CN: 旧的译文
+",
+    )
+    .unwrap();
+
+    let translations = Translations {
+        source_lang: None,
+        lang: "zh-CN".to_string(),
+        units: vec![
+            unit("not_found", "Not found", Some("未找到")),
+            unit("not_found_order", "Order not found", Some("订单未找到")),
+        ],
+    };
+    let report = import_translations(&config, &translations).unwrap();
+    assert_eq!(report.updated, ["not_found", "not_found_order"]);
+    assert_eq!(
+        std::fs::read_to_string(&config).unwrap(),
+        "supported_languages: [en, zh-CN]
+missing_translation: fallback
+groups:
+  user:
+    errors:
+      not_found:
+        code: 4004
+        message:
+          en: Not found
+          zh-CN: \"未找到\"
+  order:
+    errors:
+      not_found_order:
+        code: 4104
+        message:
+          en: Order not found
+
+          zh-CN: \"订单未找到\"
+"
+    );
+}
@@ -0,0 +1,396 @@
+// 🧪 配置内容校验测试
+
+use biz_error_codegen_core::{Error, ErrorCatalog};
+
+fn problems(yaml: &str) -> Vec<String> {
+    match ErrorCatalog::from_yaml_str(yaml) {
+        Err(Error::Validation { problems, .. }) => problems,
+        other => panic!("expected validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn duplicate_code_names_both_entries() {
+    let problems = problems(
+        "errors:
+  not_found:
+    code: 4204
+    message: { en: NOT FOUND }
+  resource_missing:
+    code: 4204
+    message: { en: RESOURCE MISSING }
+",
+    );
+    assert_eq!(
+        problems,
+        ["duplicate code 4204: errors `not_found` and `resource_missing` both use it"]
+    );
+}
+
+#[test]
+fn colliding_variant_names_both_entries() {
+    let problems = problems(
+        "errors:
+  not_found:
+    code: 4204
+    message: { en: NOT FOUND }
+  not__found:
+    code: 4205
+    message: { en: NOT FOUND }
+",
+    );
+    assert_eq!(
+        problems,
+        ["duplicate variant `NotFound`: errors `not_found` and `not__found` both map to it"]
+    );
+}
+
+#[test]
+fn invalid_identifier_is_rejected() {
+    let problems = problems(
+        "errors:
+  404_error:
+    code: 4204
+    message: { en: NOT FOUND }
+",
+    );
+    assert_eq!(
+        problems,
+        ["error `404_error` maps to `404Error`, which is not a valid Rust identifier"]
+    );
+}
+
+#[test]
+fn invalid_http_status_is_rejected() {
+    let problems = problems(
+        "errors:
+  teapot:
+    code: 4180
+    http_status: 999
+    message: { en: TEAPOT }
+",
+    );
+    assert_eq!(
+        problems,
+        ["error `teapot` has invalid http_status 999 (expected 100-599)"]
+    );
+}
+
+#[test]
+fn code_out_of_i32_range_is_rejected() {
+    let err = ErrorCatalog::from_yaml_str(
+        "errors:
+  huge:
+    code: 3000000000
+    message: { en: HUGE }
+",
+    )
+    .unwrap_err();
+    assert!(matches!(err, Error::Parse { line: Some(3), .. }), "{:?}", err);
+}
+
+#[test]
+fn all_problems_are_reported_together() {
+    let problems = problems(
+        "errors:
+  a:
+    code: 1
+    http_status: 42
+    message: { en: A }
+  b:
+    code: 1
+    message: { en: B }
+",
+    );
+    assert_eq!(problems.len(), 2);
+}
+
+const TRANSLATIONS: &str = "default_language: en
+supported_languages: [en, zh-CN, zh-TW]
+missing_translation: POLICY
+errors:
+  invalid_param:
+    code: 4000
+    message:
+      en: INVALID PARAMETER
+      zh-CN: 参数无效
+";
+
+#[test]
+fn missing_translation_is_an_error_by_default() {
+    let yaml = TRANSLATIONS.replace("missing_translation: POLICY\n", "");
+    assert_eq!(
+        problems(&yaml),
+        ["error `invalid_param` is missing a `zh-TW` translation"]
+    );
+}
+
+#[test]
+fn missing_translation_can_warn() {
+    let catalog = ErrorCatalog::from_yaml_str(&TRANSLATIONS.replace("POLICY", "warn")).unwrap();
+    assert_eq!(
+        catalog.warnings,
+        ["error `invalid_param` is missing a `zh-TW` translation"]
+    );
+}
+
+#[test]
+fn missing_translation_can_fall_back_silently() {
+    let catalog =
+        ErrorCatalog::from_yaml_str(&TRANSLATIONS.replace("POLICY", "fallback")).unwrap();
+    assert!(catalog.warnings.is_empty());
+}
+
+#[test]
+fn missing_default_language_is_always_an_error() {
+    let yaml = TRANSLATIONS
+        .replace("POLICY", "fallback")
+        .replace("      en: INVALID PARAMETER\n", "");
+    assert_eq!(
+        problems(&yaml),
+        ["error `invalid_param` is missing a `en` translation"]
+    );
+}
+
+#[test]
+fn undeclared_language_is_rejected() {
+    let yaml = TRANSLATIONS
+        .replace("POLICY", "fallback")
+        .replace("      zh-CN: 参数无效\n", "      zh-CN: 参数无效\n      ja: 無効なパラメータ\n");
+    assert_eq!(
+        problems(&yaml),
+        ["error `invalid_param` has a message in `ja`, which is not listed in supported_languages"]
+    );
+}
+
+const CATEGORIES: &str = "categories:
+  auth:
+    range: [1000, 1999]
+    http_status: 401
+  param:
+    range: [4000, 4999]
+errors:
+";
+
+#[test]
+fn category_is_inferred_from_range() {
+    let yaml = format!(
+        "{}  not_login:\n    code: 1000\n    message: {{ en: NOT LOGIN }}\n",
+        CATEGORIES
+    );
+    let catalog = ErrorCatalog::from_yaml_str(&yaml).unwrap();
+    assert_eq!(catalog.errors[0].category.as_deref(), Some("auth"));
+    assert_eq!(catalog.errors[0].http_status, 401);
+}
+
+#[test]
+fn code_outside_tagged_category_is_rejected() {
+    let yaml = format!(
+        "{}  not_login:\n    code: 4001\n    category: auth\n    message: {{ en: NOT LOGIN }}\n",
+        CATEGORIES
+    );
+    assert_eq!(
+        problems(&yaml),
+        ["error `not_login` has code 4001, outside the range 1000-1999 of category `auth`"]
+    );
+}
+
+#[test]
+fn uncategorized_code_is_rejected() {
+    let yaml = format!(
+        "{}  db_error:\n    code: 2000\n    message: {{ en: DB ERROR }}\n",
+        CATEGORIES
+    );
+    assert_eq!(
+        problems(&yaml),
+        ["error `db_error` has code 2000, which does not fall in any declared category"]
+    );
+}
+
+#[test]
+fn unknown_category_is_rejected() {
+    let yaml = format!(
+        "{}  db_error:\n    code: 2000\n    category: db\n    message: {{ en: DB ERROR }}\n",
+        CATEGORIES
+    );
+    assert_eq!(
+        problems(&yaml),
+        ["error `db_error` refers to unknown category `db`"]
+    );
+}
+
+#[test]
+fn overlapping_categories_are_rejected() {
+    let yaml = CATEGORIES
+        .replace("[4000, 4999]", "[1500, 4999]")
+        .replace("errors:\n", "errors: {}\n");
+    assert_eq!(
+        problems(&yaml),
+        ["categories `auth` (1000-1999) and `param` (1500-4999) have overlapping ranges"]
+    );
+}
+
+#[test]
+fn placeholders_must_match_across_languages() {
+    let problems = problems(
+        "errors:
+  user_not_found:
+    code: 4004
+    message:
+      en: User {user_id} not found
+      zh-CN: 用户 {id} 不存在
+",
+    );
+    assert_eq!(
+        problems,
+        ["error `user_not_found`: `zh-CN` message uses placeholders {id}, expected {user_id}"]
+    );
+}
+
+#[test]
+fn placeholders_must_match_declared_args() {
+    let problems = problems(
+        "errors:
+  quota_exceeded:
+    code: 4290
+    args: { used: u32 }
+    message:
+      en: Quota exceeded {used}/{limit}
+",
+    );
+    assert_eq!(
+        problems,
+        ["error `quota_exceeded`: `en` message uses placeholders {limit, used}, expected {used}"]
+    );
+}
+
+#[test]
+fn malformed_template_is_rejected() {
+    let problems = problems(
+        "errors:
+  broken:
+    code: 1
+    message:
+      en: Missing {brace
+",
+    );
+    assert_eq!(
+        problems,
+        ["error `broken` has an invalid `en` message: unclosed placeholder `{brace`"]
+    );
+}
+
+#[test]
+fn reserved_constructor_name_is_rejected() {
+    let problems = problems(
+        "errors:
+  code:
+    code: 1
+    message:
+      en: Bad {value}
+",
+    );
+    assert_eq!(
+        problems,
+        ["error `code` has message arguments, but its constructor would clash with `ErrorCode::code`"]
+    );
+}
+
+#[test]
+fn fallback_to_unknown_language_is_rejected() {
+    let problems = problems(
+        "supported_languages: [en, zh-CN]
+fallbacks:
+  zh-HK: [zh-TW, zh-CN]
+errors:
+  ok:
+    code: 0
+    message: { en: OK, zh-CN: 成功 }
+",
+    );
+    assert_eq!(
+        problems,
+        ["fallback chain for `zh-HK` refers to `zh-TW`, which is not a supported language"]
+    );
+}
+
+#[test]
+fn relative_type_base_uri_is_rejected() {
+    let problems = problems(
+        "type_base_uri: /errors/
+errors:
+  ok:
+    code: 0
+    message: { en: OK }
+",
+    );
+    assert_eq!(
+        problems,
+        ["type_base_uri `/errors/` is not an absolute URI (expected e.g. `https://example.com/errors/`)"]
+    );
+}
+
+#[test]
+fn each_group_gets_its_own_enum() {
+    let catalog = ErrorCatalog::from_yaml_str(
+        "groups:
+  user:
+    errors:
+      not_found:
+        code: 4004
+        message: { en: USER NOT FOUND }
+  order:
+    errors:
+      order_not_found:
+        code: 4104
+        message: { en: ORDER NOT FOUND }
+",
+    )
+    .unwrap();
+    let names: Vec<_> = catalog.groups.iter().map(|g| g.name.as_str()).collect();
+    assert_eq!(names, ["UserError", "OrderError"]);
+    assert_eq!(catalog.errors[1].group.as_deref(), Some("order"));
+}
+
+#[test]
+fn groups_cannot_mix_with_top_level_errors() {
+    let problems = problems(
+        "errors:
+  internal_error:
+    code: 5000
+    message: { en: INTERNAL ERROR }
+groups:
+  user:
+    errors:
+      not_found:
+        code: 4004
+        message: { en: NOT FOUND }
+",
+    );
+    assert_eq!(
+        problems,
+        ["top-level errors cannot be used together with groups; move `internal_error` into a group (e.g. `common`)"]
+    );
+}
+
+#[test]
+fn error_names_are_unique_across_groups() {
+    let problems = problems(
+        "groups:
+  user:
+    errors:
+      not_found:
+        code: 4004
+        message: { en: USER NOT FOUND }
+  order:
+    errors:
+      not_found:
+        code: 4104
+        message: { en: ORDER NOT FOUND }
+",
+    );
+    assert_eq!(
+        problems,
+        ["error `not_found` is defined in both group `user` and group `order`"]
+    );
+}
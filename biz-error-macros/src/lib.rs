@@ -2,13 +2,25 @@
 //
 // 使用过程宏可以让用户完全不需要 build.rs，
 // 只需在模块上添加属性即可自动生成代码
+//
+// 展开逻辑在 `biz_error_codegen_core::expand` 中（可以在测试中直接检查），这里只做 TokenStream 的转换
 
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{LitStr, ItemMod};
+
+use biz_error_codegen_core::expand;
 
 /// 从 YAML 配置自动生成错误码的属性宏
 ///
+/// 相对路径以调用方 crate 的根目录（`CARGO_MANIFEST_DIR`，即 `Cargo.toml` 所在目录）为基准。
+/// 修改配置文件（包括 `include` 的文件）后 cargo 会自动重新编译。
+///
+/// 路径（或 `include`）是目录或 glob 模式时，只有展开时已匹配到的文件会被跟踪：
+/// 之后新增的文件不会触发重新编译，需要修改已有的配置文件或执行 `cargo clean -p <crate>`。
+/// 经常增删配置文件时可以改用 build.rs 方式并传入目录，它会对目录本身输出 `cargo:rerun-if-changed`。
+///
+/// 模块的可见性、属性和文档注释保持不变，模块中手写的内容与生成的代码放在一起，
+/// 可以直接为生成的 `ErrorCode` 添加方法。
+///
 /// # 使用方式
 ///
 /// ```rust,ignore
@@ -35,185 +47,56 @@ use syn::{LitStr, ItemMod};
 ///
 /// use error_codes::ErrorCode;
 /// ```
+///
+/// **在生成的模块中添加代码：**
+/// ```rust,ignore
+/// /// 业务错误码
+/// #[generate_error_codes("biz_errors.yaml")]
+/// pub mod error_codes {
+///     impl ErrorCode {
+///         pub fn is_auth_error(&self) -> bool {
+///             (1000..2000).contains(&self.code())
+///         }
+///     }
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn generate_error_codes(args: TokenStream, input: TokenStream) -> TokenStream {
-    // 解析属性参数（YAML 文件路径）
-    let yaml_path = match syn::parse::<LitStr>(args) {
-        Ok(path) => path.value(),
-        Err(e) => {
-            let error = format!("Invalid YAML file path: {}", e);
-            return quote!(compile_error!(#error)).into();
-        }
-    };
-
-    // 解析输入的模块声明以获取模块名称
-    let module = match syn::parse::<ItemMod>(input) {
-        Ok(mod_item) => mod_item,
-        Err(e) => {
-            let error = format!("Invalid module declaration: {}", e);
-            return quote!(compile_error!(#error)).into();
-        }
-    };
-
-    let module_name = module.ident.clone();
-
-    // 在编译时生成代码
-    let expanded = match generate_error_codes_impl(&yaml_path) {
-        Ok(code) => code,
-        Err(e) => {
-            let error_msg = format!("Failed to generate error codes from '{}': {}", yaml_path, e);
-            return quote!(compile_error!(#error_msg)).into();
-        }
-    };
-
-    // 将生成的代码包装在模块中
-    let result = quote! {
-        mod #module_name {
-            #expanded
-        }
-    };
-
-    result.into()
-}
-
-/// 在编译时从 YAML 生成错误码代码
-fn generate_error_codes_impl(yaml_path: &str) -> Result<proc_macro2::TokenStream, Box<dyn std::error::Error>> {
-    // 读取 YAML 文件
-    let yaml_content = std::fs::read_to_string(yaml_path)?;
-
-    // 解析 YAML
-    let config: serde_yaml::Value = serde_yaml::from_str(&yaml_content)?;
-
-    let errors = config["errors"].as_mapping()
-        .ok_or("Missing 'errors' section in YAML")?;
-
-    let default_lang = config["default_language"]
-        .as_str()
-        .unwrap_or("en");
-
-    let mut enum_variants = Vec::new();
-    let mut code_match_arms = Vec::new();
-    let mut message_match_arms = Vec::new();
-    let mut http_status_match_arms = Vec::new();
-    let mut variant_names = Vec::new();
-
-    for (key, value) in errors {
-        let name = key.as_str().ok_or("Error key must be a string")?;
-        let code = value["code"].as_i64().ok_or("Missing 'code' field")?;
-        let code_i32 = code as i32;
-        let http_status = value["http_status"].as_i64().unwrap_or(500);
-        let http_status_u16 = http_status as u16;
-        let messages = value["message"].as_mapping()
-            .ok_or("Missing 'message' field")?;
-
-        // 转换为 PascalCase
-        let enum_name = to_pascal_case(name);
-        variant_names.push(enum_name.clone());
-
-        // 生成枚举变体
-        let doc_msg = messages
-            .get(serde_yaml::Value::String(default_lang.to_string()))
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
-        enum_variants.push(quote! {
-            #[doc = #doc_msg]
-            #enum_name,
-        });
-
-        // 生成 code() 方法分支
-        code_match_arms.push(quote! {
-            ErrorCode::#enum_name => #code_i32,
-        });
-
-        // 生成 message() 方法分支
-        for (lang, msg) in messages {
-            let lang_str = lang.as_str().ok_or("Language key must be a string")?;
-            let msg_str = msg.as_str().ok_or("Message value must be a string")?;
-            message_match_arms.push(quote! {
-                (ErrorCode::#enum_name, #lang_str) => #msg_str,
-            });
-        }
-
-        let fallback_msg = messages
-            .get(serde_yaml::Value::String(default_lang.to_string()))
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
-        message_match_arms.push(quote! {
-            (ErrorCode::#enum_name, _) => #fallback_msg,
-        });
-
-        // 生成 http_status() 方法分支
-        http_status_match_arms.push(quote! {
-            ErrorCode::#enum_name => ::axum::http::StatusCode::from_u16(#http_status_u16).unwrap(),
-        });
-    }
-
-    let all_constants = variant_names.iter()
-        .map(|v| quote! { ErrorCode::#v })
-        .collect::<Vec<_>>();
-
-    // 生成完整的错误码模块
-    Ok(quote! {
-        // 导入 ErrorCode trait
-        use ::biz_error::ErrorCode as ErrorCodeTrait;
-
-        // 自动生成的业务错误码枚举
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-        pub enum ErrorCode {
-            #(#enum_variants)*
-        }
-
-        // 实现 biz_error::ErrorCode trait
-        impl ::biz_error::ErrorCode for ErrorCode {
-            fn code(&self) -> i32 {
-                match self {
-                    #(#code_match_arms)*
-                }
-            }
-
-            fn message(&self) -> &'static str {
-                self.message_lang(#default_lang)
-            }
-
-            fn message_lang(&self, lang: &str) -> &'static str {
-                match (self, lang.as_ref()) {
-                    #(#message_match_arms)*
-                }
-            }
-
-            fn http_status(&self) -> ::axum::http::StatusCode {
-                match self {
-                    #(#http_status_match_arms)*
-                }
-            }
-        }
-
-        impl ::std::fmt::Display for ErrorCode {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                write!(f, "[{}] {}", self.code(), self.message())
-            }
-        }
-
-        impl ::std::error::Error for ErrorCode {}
-
-        /// 所有错误码常量列表
-        pub const ALL_ERROR_CODES: &[ErrorCode] = &[#(#all_constants),*];
-    })
+    expand::expand_module(args.into(), input.into()).into()
 }
 
-/// 将 snake_case 转换为 PascalCase
-fn to_pascal_case(s: &str) -> proc_macro2::Ident {
-    let converted: String = s.split('_')
-        .map(|word| {
-            let mut chars = word.chars();
-            match chars.next() {
-                None => String::new(),
-                Some(first) => {
-                    first.to_uppercase().collect::<String>() + chars.as_str()
-                }
-            }
-        })
-        .collect();
-
-    proc_macro2::Ident::new(&converted, proc_macro2::Span::call_site())
+/// 在枚举上直接声明错误码的 derive 宏（不需要 YAML 文件）
+///
+/// 生成与 YAML 方式相同的 `ErrorCode` trait 实现、`Display`、`Error` 和带参数消息的构造函数，
+/// 校验规则（重复错误码、翻译完整性等）也完全相同。
+/// `ALL_ERROR_CODES` 生成为关联常量（`MyError::ALL_ERROR_CODES`）。
+///
+/// 枚举需要自行 derive `ErrorCode` trait 要求的 `Debug, Clone, Copy, PartialEq, Eq`。
+///
+/// # 属性
+///
+/// - 枚举上（均可选）：`default_language = "en"`、`supported_languages = ["en", "zh-CN"]`、
+///   `missing_translation = "warn"`、`type_base_uri = "https://..."`
+/// - 变体上：`code = 4000`（必填）、`http = 400`、各语言消息 `en = "..."`、`zh_CN = "..."`
+///   （语言名中的 `_` 对应 `-`）
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// use biz_error::BizErrorCode;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, BizErrorCode)]
+/// #[biz(default_language = "en", supported_languages = ["en", "zh-CN"])]
+/// pub enum ErrorCode {
+///     #[biz(code = 4000, http = 400, en = "INVALID PARAMETER", zh_CN = "参数无效")]
+///     InvalidParam,
+///     #[biz(code = 4004, http = 404, en = "User {user_id} not found", zh_CN = "用户 {user_id} 不存在")]
+///     UserNotFound,
+/// }
+///
+/// let error = ErrorCode::user_not_found(42);
+/// ```
+#[proc_macro_derive(BizErrorCode, attributes(biz))]
+pub fn derive_biz_error_code(input: TokenStream) -> TokenStream {
+    expand::expand_derive(input.into()).into()
 }
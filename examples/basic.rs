@@ -1,9 +1,14 @@
 /// 基础使用示例
-use biz_error::{AppError, ErrorCode};
+use biz_error::{AppError, ErrorCode as _, OptionExt, ResultExt, generate_error_codes};
 use serde_json::json;
 
+#[generate_error_codes("biz_errors.yaml.example")]
+mod error_codes {}
+
+use error_codes::ErrorCode;
+
 /// 定义用户相关的业务错误类型
-pub struct UserError(AppError);
+pub struct UserError(AppError<ErrorCode>);
 
 impl UserError {
     /// 用户不存在
@@ -64,8 +69,14 @@ pub fn get_user(id: u64) -> Result<db::User, UserError> {
         .and_then(|user| user.ok_or_else(|| UserError::not_found(id)))
 }
 
-#[tokio::main]
-async fn main() {
+/// 获取用户（使用 ResultExt / OptionExt，原始错误保留为底层错误）
+pub fn find_user(id: u64) -> Result<db::User, AppError<ErrorCode>> {
+    db::find_user(id)
+        .biz_with(|_| AppError::new(ErrorCode::InvalidParam).with_data(json!({"user_id": id})))?
+        .or_biz(ErrorCode::NotFound)
+}
+
+fn main() {
     println!("=== biz-error 基础使用示例 ===\n");
 
     // 示例 1: 基础错误
@@ -105,4 +116,12 @@ async fn main() {
         Ok(user) => println!("找到用户: {}", user.name),
         Err(e) => println!("错误: {}", e.0),
     }
+
+    // 示例 5: 使用扩展 trait（{:#} 会输出底层错误）
+    println!("\n=== 扩展 trait 示例 ===\n");
+
+    match find_user(0) {
+        Ok(user) => println!("找到用户: {}", user.name),
+        Err(e) => println!("错误: {:#}", e),
+    }
 }
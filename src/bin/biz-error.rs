@@ -0,0 +1,327 @@
+// 📦 biz-error 命令行工具 - 不编译服务即可校验和维护错误码配置（可以放在 pre-commit 中）
+//
+// ```text
+// biz-error lint                                     与过程宏相同的全部检查
+// biz-error list                                     错误码一览表
+// biz-error next-code client                         分类中第一个未被使用的错误码
+// biz-error new user_locked --category client --http 423 -m "User is locked" -m zh-CN=用户已锁定
+// biz-error diff old/biz_errors.yaml                 与另一份配置比较
+// ```
+//
+// 默认读取当前目录下的 `biz_errors.yaml`，可以用 `--config` 指定（与 `#[generate_error_codes]` 读取的是同一份配置）。
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use biz_error_codegen_core::{ErrorCatalog, ErrorEntry, NewError, add_error, next_code};
+use clap::{ArgGroup, Args, Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "biz-error", version, about = "校验和维护 biz-error 错误码配置")]
+struct Cli {
+    /// 配置文件（也可以是目录或 glob 模式）
+    #[arg(short, long, global = true, default_value = "biz_errors.yaml")]
+    config: PathBuf,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 校验配置：格式、重复的错误码和变体名、分类范围、消息模板、翻译完整性等
+    Lint {
+        /// 有警告时也以失败退出
+        #[arg(long)]
+        deny_warnings: bool,
+    },
+    /// 列出错误码
+    List {
+        /// 只列出该分类的错误
+        #[arg(long)]
+        category: Option<String>,
+        /// 只列出该分组的错误
+        #[arg(long)]
+        group: Option<String>,
+        /// 消息的语言（默认为 default_language）
+        #[arg(long)]
+        lang: Option<String>,
+    },
+    /// 输出分类中第一个未被使用的错误码
+    NextCode {
+        /// 分类名
+        category: String,
+    },
+    /// 新增一个错误，插入到同一范围中相邻的错误旁边
+    New(NewArgs),
+    /// 与另一份配置比较，列出新增、删除和修改的错误
+    Diff {
+        /// 作为比较基准的旧配置
+        old: PathBuf,
+        /// 删除错误或修改错误码、HTTP 状态码时以失败退出
+        #[arg(long)]
+        deny_breaking: bool,
+    },
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("code_source").required(true).args(["code", "category"])))]
+struct NewArgs {
+    /// 错误名（snake_case）
+    key: String,
+    /// 错误码
+    #[arg(long)]
+    code: Option<i32>,
+    /// 使用该分类中第一个未被使用的错误码
+    #[arg(long)]
+    category: Option<String>,
+    /// HTTP 状态码（省略时使用分类的默认值或 500）
+    #[arg(long = "http", value_name = "STATUS")]
+    http_status: Option<u16>,
+    /// 所属分组（省略时使用相邻错误所在的分组）
+    #[arg(long)]
+    group: Option<String>,
+    /// 错误说明
+    #[arg(long)]
+    description: Option<String>,
+    /// 消息：`LANG=TEXT`，省略语言时为默认语言；可以多次指定
+    #[arg(short, long = "message", value_name = "[LANG=]TEXT")]
+    messages: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Lint { deny_warnings } => lint(&cli.config, deny_warnings),
+        Command::List {
+            category,
+            group,
+            lang,
+        } => list(&cli.config, category.as_deref(), group.as_deref(), lang.as_deref()),
+        Command::NextCode { category } => next(&cli.config, &category),
+        Command::New(args) => new(&cli.config, args),
+        Command::Diff { old, deny_breaking } => diff(&old, &cli.config, deny_breaking),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn load(config: &Path) -> Result<ErrorCatalog, String> {
+    ErrorCatalog::from_path(config).map_err(|e| e.to_string())
+}
+
+/// 校验配置，返回是否通过
+fn lint(config: &Path, deny_warnings: bool) -> Result<bool, String> {
+    let catalog = load(config)?;
+    for warning in &catalog.warnings {
+        eprintln!("warning: {}", warning);
+    }
+    let summary = match catalog.warnings.len() {
+        0 => "no problems found".to_string(),
+        1 => "1 warning".to_string(),
+        n => format!("{} warnings", n),
+    };
+    println!(
+        "{}: {} error codes in {} file(s), {}",
+        config.display(),
+        catalog.errors.len(),
+        catalog.files.len(),
+        summary
+    );
+    Ok(!deny_warnings || catalog.warnings.is_empty())
+}
+
+fn list(
+    config: &Path,
+    category: Option<&str>,
+    group: Option<&str>,
+    lang: Option<&str>,
+) -> Result<bool, String> {
+    let catalog = load(config)?;
+    let lang = lang.unwrap_or(&catalog.default_language);
+    let mut errors: Vec<&ErrorEntry> = catalog
+        .errors
+        .iter()
+        .filter(|e| category.is_none() || e.category.as_deref() == category)
+        .filter(|e| group.is_none() || e.group.as_deref() == group)
+        .collect();
+    errors.sort_by_key(|e| e.code);
+
+    let with_category = !catalog.categories.is_empty();
+    let with_group = !catalog.groups.is_empty();
+    let mut rows = vec![row(
+        &["CODE", "HTTP", "KEY"],
+        with_category.then_some("CATEGORY"),
+        with_group.then_some("GROUP"),
+        "MESSAGE",
+    )];
+    for entry in errors {
+        rows.push(row(
+            &[&entry.code.to_string(), &entry.http_status.to_string(), &entry.key],
+            with_category.then(|| entry.category.as_deref().unwrap_or("-")),
+            with_group.then(|| entry.group.as_deref().unwrap_or("-")),
+            entry.message(lang).unwrap_or("-"),
+        ));
+    }
+    print!("{}", table(&rows));
+    Ok(true)
+}
+
+fn next(config: &Path, category: &str) -> Result<bool, String> {
+    let catalog = load(config)?;
+    let code = next_code(&catalog, category).map_err(|e| e.to_string())?;
+    println!("{}", code);
+    Ok(true)
+}
+
+fn row(
+    leading: &[&str],
+    category: Option<&str>,
+    group: Option<&str>,
+    message: &str,
+) -> Vec<String> {
+    let mut cells: Vec<String> = leading.iter().map(|s| s.to_string()).collect();
+    cells.extend(category.map(String::from));
+    cells.extend(group.map(String::from));
+    // 多行消息只显示第一行
+    cells.push(message.lines().next().unwrap_or_default().to_string());
+    cells
+}
+
+/// 按列对齐（最后一列不补空格）
+fn table(rows: &[Vec<String>]) -> String {
+    let columns = rows.first().map_or(0, Vec::len);
+    let widths: Vec<usize> = (0..columns)
+        .map(|i| rows.iter().map(|r| r[i].chars().count()).max().unwrap_or(0))
+        .collect();
+    let mut out = String::new();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            out.push_str(cell);
+            if i + 1 < columns {
+                let padding = widths[i] - cell.chars().count() + 2;
+                out.extend(std::iter::repeat_n(' ', padding));
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn new(config: &Path, args: NewArgs) -> Result<bool, String> {
+    let catalog = load(config)?;
+    let code = match (args.code, args.category) {
+        (Some(code), _) => code,
+        (None, Some(category)) => next_code(&catalog, &category).map_err(|e| e.to_string())?,
+        (None, None) => unreachable!("clap requires --code or --category"),
+    };
+
+    // `LANG=` 前缀是配置中用到的语言时视为语言；像语言标签却不在配置中时报错（多半是拼错了），
+    // 其他情况下整段作为默认语言的消息，消息本身可以包含 `=`
+    let languages = catalog.languages();
+    let mut messages: Vec<(String, String)> = Vec::new();
+    for message in &args.messages {
+        let (lang, text) = match message.split_once('=') {
+            Some((lang, text)) if languages.contains(&lang) => (lang, text),
+            Some((lang, _)) if is_language_tag(lang) => {
+                return Err(format!(
+                    "unknown language `{}` (expected one of: {}); write `{}={}` if the message itself contains `=`",
+                    lang,
+                    languages.join(", "),
+                    catalog.default_language,
+                    message
+                ));
+            }
+            _ => (catalog.default_language.as_str(), message.as_str()),
+        };
+        if messages.iter().any(|(l, _)| l == lang) {
+            return Err(format!("message for `{}` is given more than once", lang));
+        }
+        messages.push((lang.to_string(), text.to_string()));
+    }
+
+    let error = NewError {
+        key: args.key,
+        code,
+        http_status: args.http_status,
+        group: args.group,
+        description: args.description,
+        messages,
+    };
+    let file = add_error(config, &error).map_err(|e| e.to_string())?;
+    println!("added `{}` (code {}) to {}", error.key, error.code, file.display());
+    Ok(true)
+}
+
+/// 形如 BCP 47 语言标签：2-3 个字母的主语言，之后是 1-8 个字母或数字的子标签，如 `zh-Hant-TW`
+fn is_language_tag(tag: &str) -> bool {
+    let mut subtags = tag.split('-');
+    let primary = subtags.next().unwrap_or_default();
+    (2..=3).contains(&primary.len())
+        && primary.chars().all(|c| c.is_ascii_alphabetic())
+        && subtags.all(|s| (1..=8).contains(&s.len()) && s.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// 比较两份配置，返回是否没有破坏性修改（或未要求检查）
+fn diff(old: &Path, new: &Path, deny_breaking: bool) -> Result<bool, String> {
+    let old = load(old)?;
+    let new = load(new)?;
+    let mut changes = 0;
+    let mut breaking = 0;
+
+    for entry in &new.errors {
+        let Some(before) = old.errors.iter().find(|e| e.key == entry.key) else {
+            println!("+ {} ({})", entry.key, entry.code);
+            changes += 1;
+            continue;
+        };
+        if before.code != entry.code {
+            println!("~ {}: code {} -> {} (breaking)", entry.key, before.code, entry.code);
+            changes += 1;
+            breaking += 1;
+        }
+        if before.http_status != entry.http_status {
+            println!(
+                "~ {}: http_status {} -> {} (breaking)",
+                entry.key, before.http_status, entry.http_status
+            );
+            changes += 1;
+            breaking += 1;
+        }
+        let mut langs: Vec<&str> = before.messages.iter().map(|(l, _)| l.as_str()).collect();
+        for (lang, _) in &entry.messages {
+            if !langs.contains(&lang.as_str()) {
+                langs.push(lang);
+            }
+        }
+        for lang in langs {
+            match (before.message(lang), entry.message(lang)) {
+                (Some(a), Some(b)) if a != b => {
+                    println!("~ {}: message[{}] {:?} -> {:?}", entry.key, lang, a, b)
+                }
+                (Some(a), None) => println!("~ {}: message[{}] {:?} removed", entry.key, lang, a),
+                (None, Some(b)) => println!("~ {}: message[{}] {:?} added", entry.key, lang, b),
+                _ => continue,
+            }
+            changes += 1;
+        }
+    }
+    for entry in &old.errors {
+        if !new.errors.iter().any(|e| e.key == entry.key) {
+            println!("- {} ({}) (breaking)", entry.key, entry.code);
+            changes += 1;
+            breaking += 1;
+        }
+    }
+
+    if changes == 0 {
+        println!("no changes");
+    }
+    Ok(!(deny_breaking && breaking > 0))
+}
@@ -0,0 +1,292 @@
+// 📦 运行时消息目录 - 不重新部署即可覆盖编译时生成的消息
+//
+// 文件结构与配置中的 `message:` 块相同，以错误名（`ErrorCode::key`）为键：
+//
+// ```yaml
+// user_not_found:
+//   zh-CN: "用户 {user_id} 不存在"
+// internal_error:
+//   en: "Something went wrong, please try again later"
+// ```
+//
+// 渲染消息时先查全局消息目录，没有对应条目时依次使用全局 `MessageProvider`、编译时生成的消息（`ErrorCode::message_lang`）。
+// 重新加载时先完整解析新文件，再整体替换全局目录，正在渲染的请求只会看到替换前或替换后的目录；
+// 解析失败时保留原来的目录。
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+/// 运行时消息目录：错误名 -> 语言 -> 消息模板
+///
+/// # Examples
+///
+/// ```
+/// use biz_error::MessageCatalog;
+///
+/// let catalog = MessageCatalog::from_yaml_str(
+///     r#"
+/// user_not_found:
+///   zh-CN: "用户 {user_id} 不存在"
+/// "#,
+/// )
+/// .unwrap();
+/// assert_eq!(catalog.message("user_not_found", "zh-CN"), Some("用户 {user_id} 不存在"));
+/// assert_eq!(catalog.message("user_not_found", "en"), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageCatalog {
+    messages: HashMap<String, HashMap<String, String>>,
+}
+
+impl MessageCatalog {
+    /// 创建空的消息目录
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 从 YAML 字符串解析
+    pub fn from_yaml_str(content: &str) -> Result<Self, CatalogError> {
+        let messages = serde_yaml::from_str::<Option<_>>(content).map_err(|e| CatalogError::Parse {
+            path: None,
+            message: e.to_string(),
+        })?;
+        Ok(Self {
+            messages: messages.unwrap_or_default(),
+        })
+    }
+
+    /// 从 JSON 字符串解析
+    pub fn from_json_str(content: &str) -> Result<Self, CatalogError> {
+        let messages = serde_json::from_str(content).map_err(|e| CatalogError::Parse {
+            path: None,
+            message: e.to_string(),
+        })?;
+        Ok(Self { messages })
+    }
+
+    /// 从文件加载（扩展名为 `.json` 时按 JSON 解析，否则按 YAML 解析）
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, CatalogError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|source| CatalogError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let is_json = path.extension().is_some_and(|ext| ext == "json");
+        let result = if is_json {
+            Self::from_json_str(&content)
+        } else {
+            Self::from_yaml_str(&content)
+        };
+        result.map_err(|e| match e {
+            CatalogError::Parse { message, .. } => CatalogError::Parse {
+                path: Some(path.to_path_buf()),
+                message,
+            },
+            e => e,
+        })
+    }
+
+    /// 添加（或替换）一条消息
+    pub fn insert(&mut self, key: impl Into<String>, lang: impl Into<String>, message: impl Into<String>) {
+        self.messages
+            .entry(key.into())
+            .or_default()
+            .insert(lang.into(), message.into());
+    }
+
+    /// 添加一条消息（构建器风格）
+    pub fn with_message(
+        mut self,
+        key: impl Into<String>,
+        lang: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.insert(key, lang, message);
+        self
+    }
+
+    /// 查找消息模板（语言需精确匹配）
+    pub fn message(&self, key: &str, lang: &str) -> Option<&str> {
+        self.messages.get(key)?.get(lang).map(String::as_str)
+    }
+
+    /// 消息条数
+    pub fn len(&self) -> usize {
+        self.messages.values().map(HashMap::len).sum()
+    }
+
+    /// 是否没有任何消息
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// 加载或监听消息目录时的错误
+#[derive(Debug)]
+pub enum CatalogError {
+    /// 读取文件失败
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// 文件内容不是合法的消息目录
+    Parse {
+        path: Option<PathBuf>,
+        message: String,
+    },
+    /// 无法监听文件变化
+    Watch(notify::Error),
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatalogError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            CatalogError::Parse { path, message } => match path {
+                Some(path) => write!(f, "{}: {}", path.display(), message),
+                None => write!(f, "<string>: {}", message),
+            },
+            CatalogError::Watch(e) => write!(f, "failed to watch message catalog: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CatalogError::Io { source, .. } => Some(source),
+            CatalogError::Watch(e) => Some(e),
+            CatalogError::Parse { .. } => None,
+        }
+    }
+}
+
+/// 全局消息目录，未设置时只使用编译时生成的消息
+static GLOBAL_CATALOG: RwLock<Option<Arc<MessageCatalog>>> = RwLock::new(None);
+
+/// 设置全局消息目录
+///
+/// 整体替换之前的目录，对之后渲染的所有 `AppError` 消息生效。
+///
+/// # Examples
+///
+/// ```
+/// use biz_error::{MessageCatalog, set_message_catalog};
+///
+/// set_message_catalog(
+///     MessageCatalog::new().with_message("user_not_found", "zh-CN", "找不到用户 {user_id}"),
+/// );
+/// ```
+pub fn set_message_catalog(catalog: MessageCatalog) {
+    let mut global = GLOBAL_CATALOG.write().unwrap_or_else(|e| e.into_inner());
+    *global = Some(Arc::new(catalog));
+}
+
+/// 清除全局消息目录，恢复为只使用编译时生成的消息
+pub fn clear_message_catalog() {
+    let mut global = GLOBAL_CATALOG.write().unwrap_or_else(|e| e.into_inner());
+    *global = None;
+}
+
+/// 获取当前的全局消息目录
+pub fn message_catalog() -> Option<Arc<MessageCatalog>> {
+    let global = GLOBAL_CATALOG.read().unwrap_or_else(|e| e.into_inner());
+    global.clone()
+}
+
+/// 在全局消息目录中查找消息模板
+pub(crate) fn lookup(key: &str, lang: &str) -> Option<String> {
+    let global = GLOBAL_CATALOG.read().unwrap_or_else(|e| e.into_inner());
+    global.as_ref()?.message(key, lang).map(str::to_string)
+}
+
+/// 加载消息目录文件设为全局目录，并在文件变化时自动重新加载
+///
+/// 返回的 `CatalogWatcher` 被丢弃时停止监听（已加载的目录保持不变）。
+/// 监听的是文件所在目录，编辑器以「写临时文件再重命名」的方式保存时同样生效；
+/// 重新加载失败（如文件写到一半）时保留原来的目录，原因见 `CatalogWatcher::last_error`。
+///
+/// # Examples
+///
+/// ```no_run
+/// let watcher = biz_error::watch_message_catalog("config/messages.yaml")?;
+/// // 保持 watcher 存活直到服务退出
+/// # drop(watcher);
+/// # Ok::<(), biz_error::CatalogError>(())
+/// ```
+pub fn watch_message_catalog(path: impl AsRef<Path>) -> Result<CatalogWatcher, CatalogError> {
+    let path = path.as_ref().to_path_buf();
+    set_message_catalog(MessageCatalog::from_path(&path)?);
+
+    let last_error = Arc::new(Mutex::new(None));
+    let file_name = path.file_name().map(|name| name.to_os_string());
+    let reload = {
+        let path = path.clone();
+        let last_error = Arc::clone(&last_error);
+        move |event: notify::Result<notify::Event>| {
+            let Ok(event) = event else {
+                return;
+            };
+            if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                return;
+            }
+            if !event
+                .paths
+                .iter()
+                .any(|p| p.file_name() == file_name.as_deref())
+            {
+                return;
+            }
+
+            // 写入文件时会先截断，空文件视为尚未写完，等待下一次变化
+            if std::fs::metadata(&path).is_ok_and(|meta| meta.len() == 0) {
+                return;
+            }
+            let result = MessageCatalog::from_path(&path).map(set_message_catalog);
+            let mut last_error = last_error.lock().unwrap_or_else(|e| e.into_inner());
+            *last_error = result.err().map(|e| e.to_string());
+        }
+    };
+
+    let mut watcher = notify::recommended_watcher(reload).map_err(CatalogError::Watch)?;
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    watcher
+        .watch(dir, RecursiveMode::NonRecursive)
+        .map_err(CatalogError::Watch)?;
+
+    Ok(CatalogWatcher {
+        _watcher: watcher,
+        last_error,
+    })
+}
+
+/// 消息目录的文件监听句柄，由 `watch_message_catalog` 返回
+pub struct CatalogWatcher {
+    _watcher: RecommendedWatcher,
+    last_error: Arc<Mutex<Option<String>>>,
+}
+
+impl CatalogWatcher {
+    /// 最近一次重新加载失败的原因（成功重新加载后清除）
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+impl fmt::Debug for CatalogWatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CatalogWatcher")
+            .field("last_error", &self.last_error())
+            .finish_non_exhaustive()
+    }
+}
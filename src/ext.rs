@@ -0,0 +1,108 @@
+// 📦 扩展 trait - 将 Result / Option 转换为 AppError
+//
+// 替代调用处重复的样板代码：
+//
+// ```rust,ignore
+// .map_err(|e| AppError::new(ErrorCode::DbError).with_msg(e.to_string()))?  →  .biz(ErrorCode::DbError)?
+// .ok_or_else(|| AppError::new(ErrorCode::NotFound))?                       →  .or_biz(ErrorCode::NotFound)?
+// ```
+//
+// 转换时原始错误作为 `AppError` 的底层错误保留（`with_source`），不会出现在响应中。
+
+use std::error::Error as StdError;
+
+use crate::{AppError, ErrorCode};
+
+/// `Result` 转换为 `Result<T, AppError<E>>`
+///
+/// 原始错误会保留为 `AppError` 的底层错误（可通过 `std::error::Error::source` 获取）。
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use biz_error::ResultExt;
+/// use serde_json::json;
+///
+/// // 只指定错误码
+/// let user = db::find_user(id).biz(ErrorCode::DbError)?;
+///
+/// // 根据原始错误附加上下文数据
+/// let user = db::find_user(id).biz_with(|e| {
+///     AppError::new(ErrorCode::DbError).with_data(json!({ "user_id": id, "kind": e.kind() }))
+/// })?;
+/// ```
+pub trait ResultExt<T, Err> {
+    /// 将错误转换为指定错误码的 `AppError`
+    fn biz<E: ErrorCode>(self, error_code: E) -> Result<T, AppError<E>>;
+
+    /// 用闭包根据原始错误构造 `AppError`（可附加消息和数据）
+    ///
+    /// 闭包没有设置底层错误时，原始错误会自动设置为底层错误。
+    fn biz_with<E, F>(self, f: F) -> Result<T, AppError<E>>
+    where
+        E: ErrorCode,
+        F: FnOnce(&Err) -> AppError<E>;
+}
+
+impl<T, Err> ResultExt<T, Err> for Result<T, Err>
+where
+    Err: Into<Box<dyn StdError + Send + Sync>>,
+{
+    fn biz<E: ErrorCode>(self, error_code: E) -> Result<T, AppError<E>> {
+        self.map_err(|err| AppError::new(error_code).with_source(err))
+    }
+
+    fn biz_with<E, F>(self, f: F) -> Result<T, AppError<E>>
+    where
+        E: ErrorCode,
+        F: FnOnce(&Err) -> AppError<E>,
+    {
+        self.map_err(|err| {
+            let error = f(&err);
+            if error.source.is_some() {
+                error
+            } else {
+                error.with_source(err)
+            }
+        })
+    }
+}
+
+/// `Option` 转换为 `Result<T, AppError<E>>`
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use biz_error::OptionExt;
+/// use serde_json::json;
+///
+/// let user = users.get(&id).or_biz(ErrorCode::NotFound)?;
+///
+/// let user = users.get(&id).or_biz_with(|| {
+///     AppError::new(ErrorCode::NotFound).with_data(json!({ "user_id": id }))
+/// })?;
+/// ```
+pub trait OptionExt<T> {
+    /// 为 `None` 时返回指定错误码的 `AppError`
+    fn or_biz<E: ErrorCode>(self, error_code: E) -> Result<T, AppError<E>>;
+
+    /// 为 `None` 时用闭包构造 `AppError`（可附加消息和数据）
+    fn or_biz_with<E, F>(self, f: F) -> Result<T, AppError<E>>
+    where
+        E: ErrorCode,
+        F: FnOnce() -> AppError<E>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn or_biz<E: ErrorCode>(self, error_code: E) -> Result<T, AppError<E>> {
+        self.ok_or_else(|| AppError::new(error_code))
+    }
+
+    fn or_biz_with<E, F>(self, f: F) -> Result<T, AppError<E>>
+    where
+        E: ErrorCode,
+        F: FnOnce() -> AppError<E>,
+    {
+        self.ok_or_else(f)
+    }
+}
@@ -0,0 +1,61 @@
+// 📦 Fluent 消息渲染 - 用生成代码中嵌入的 `.ftl` 资源渲染 AppError 消息
+//
+// 模板参数（`AppError::with_arg`）作为 Fluent 变量传入，能解析为数字的参数按数字传入，
+// 从而按语言的复数规则选择变体（`{ $count -> [one] ... *[other] ... }`）。
+// 每个资源 + 语言只构建一次 FluentBundle，之后复用。
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use unic_langid::LanguageIdentifier;
+
+type Bundle = FluentBundle<FluentResource>;
+
+/// (资源地址, 语言) -> 已构建的 FluentBundle
+type Bundles = HashMap<(usize, &'static str), Arc<Bundle>>;
+
+static BUNDLES: LazyLock<RwLock<Bundles>> = LazyLock::new(Default::default);
+
+/// 渲染资源中的消息，资源中没有该消息（该语言使用普通消息）时返回 `None`
+pub(crate) fn format(
+    resource: &'static str,
+    lang: &'static str,
+    id: &str,
+    args: &[(&str, String)],
+) -> Option<String> {
+    let bundle = bundle(resource, lang);
+    let pattern = bundle.get_message(id)?.value()?;
+
+    let mut fluent_args = FluentArgs::with_capacity(args.len());
+    for (name, value) in args {
+        fluent_args.set(*name, FluentValue::try_number(value.as_str()));
+    }
+    // 缺少变量等错误不影响输出（对应位置保留为 `{$name}`），资源已在代码生成时校验
+    let mut errors = Vec::new();
+    Some(
+        bundle
+            .format_pattern(pattern, Some(&fluent_args), &mut errors)
+            .into_owned(),
+    )
+}
+
+fn bundle(resource: &'static str, lang: &'static str) -> Arc<Bundle> {
+    let key = (resource.as_ptr() as usize, lang);
+    if let Some(bundle) = BUNDLES.read().unwrap_or_else(|e| e.into_inner()).get(&key) {
+        return Arc::clone(bundle);
+    }
+
+    let locale: LanguageIdentifier = lang.parse().unwrap_or_default();
+    let mut bundle = Bundle::new_concurrent(vec![locale]);
+    // 错误消息通常嵌入 JSON 响应，不需要 Unicode 双向隔离字符
+    bundle.set_use_isolating(false);
+    let _ = bundle.add_builtins();
+    let parsed = FluentResource::try_new(resource.to_string()).unwrap_or_else(|(partial, _)| partial);
+    let _ = bundle.add_resource(parsed);
+
+    let bundle = Arc::new(bundle);
+    let mut bundles = BUNDLES.write().unwrap_or_else(|e| e.into_inner());
+    Arc::clone(bundles.entry(key).or_insert(bundle))
+}
@@ -0,0 +1,222 @@
+// 📦 响应格式 - 决定 AppError 序列化成什么样的响应体
+//
+// 默认格式为 `{code, msg, data}`（`DefaultFormatter`），内置 RFC 9457 格式（`ProblemDetailsFormatter`），
+// 可以全局替换（`set_response_formatter`），也可以按路由替换（`FormatResponseLayer`）：
+//
+// ```text
+// AppError ──> ErrorInfo (与错误码类型无关的视图) ──> ResponseFormatter ──> 响应体
+// ```
+
+use std::borrow::Cow;
+use std::sync::{Arc, RwLock};
+
+use serde_json::Value;
+
+use crate::ErrorResponse;
+
+/// 交给 `ResponseFormatter` 的错误信息
+///
+/// 由 `AppError::info_lang` 创建，消息已按语言选择并渲染了模板参数。
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ErrorInfo<'a> {
+    /// 数字错误码
+    pub code: i32,
+    /// 配置中的错误名（`ErrorCode::key`）
+    pub key: &'static str,
+    /// Problem Details `type` URI 的前缀（`ErrorCode::type_base_uri`）
+    pub type_base_uri: Option<&'static str>,
+    /// 错误码对应的消息（已渲染模板参数，不受自定义消息影响）
+    pub message: Cow<'a, str>,
+    /// 自定义消息（`AppError::with_msg`）
+    pub custom_msg: Option<&'a str>,
+    /// 消息实际使用的语言
+    pub lang: &'static str,
+    /// 消息模板参数
+    pub args: &'a [(&'static str, String)],
+    /// 附加数据
+    pub data: Option<&'a Value>,
+    /// HTTP 状态码
+    pub status: u16,
+}
+
+impl ErrorInfo<'_> {
+    /// 最终展示的消息：设置了自定义消息时为自定义消息，否则为错误码对应的消息
+    pub fn msg(&self) -> &str {
+        self.custom_msg.unwrap_or(&self.message)
+    }
+}
+
+/// 错误响应格式
+///
+/// 实现此 trait 即可自定义错误响应体，例如对接网关要求的 `{errcode, errmsg}`。
+/// 闭包 `Fn(&ErrorInfo) -> Value` 也实现了此 trait。
+///
+/// # Examples
+///
+/// ```
+/// use biz_error::{ErrorInfo, ResponseFormatter};
+/// use serde_json::{json, Value};
+///
+/// struct GatewayFormatter;
+///
+/// impl ResponseFormatter for GatewayFormatter {
+///     fn format(&self, error: &ErrorInfo<'_>) -> Value {
+///         json!({ "errcode": error.code, "errmsg": error.msg() })
+///     }
+/// }
+///
+/// // 等价的闭包写法
+/// let formatter = |error: &ErrorInfo<'_>| json!({ "errcode": error.code, "errmsg": error.msg() });
+/// # fn assert_formatter(_: impl ResponseFormatter) {}
+/// # assert_formatter(formatter);
+/// ```
+pub trait ResponseFormatter: Send + Sync + 'static {
+    /// 生成响应体
+    fn format(&self, error: &ErrorInfo<'_>) -> Value;
+
+    /// 响应的 `Content-Type`
+    fn content_type(&self) -> &'static str {
+        "application/json"
+    }
+}
+
+impl<F> ResponseFormatter for F
+where
+    F: Fn(&ErrorInfo<'_>) -> Value + Send + Sync + 'static,
+{
+    fn format(&self, error: &ErrorInfo<'_>) -> Value {
+        self(error)
+    }
+}
+
+/// 默认响应格式：`{code, msg, data}`（即 `ErrorResponse`）
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultFormatter;
+
+impl ResponseFormatter for DefaultFormatter {
+    fn format(&self, error: &ErrorInfo<'_>) -> Value {
+        let resp = ErrorResponse {
+            code: error.code,
+            msg: error.msg().to_string(),
+            data: error.data.cloned(),
+        };
+        serde_json::to_value(resp).expect("ErrorResponse is always serializable")
+    }
+}
+
+/// RFC 9457 Problem Details 响应格式（`application/problem+json`）
+///
+/// - `type`：`type_base_uri` + 错误名，未配置 `type_base_uri` 时为 `about:blank`
+/// - `title`：按语言选择的错误码消息
+/// - `status`：HTTP 状态码
+/// - `detail`：自定义消息（`AppError::with_msg`），未设置时省略
+/// - `code`：数字错误码（扩展成员）
+/// - 附加数据为对象时，其字段作为扩展成员合并到文档中（不覆盖上述成员），否则放在 `data` 中
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "type": "https://example.com/errors/user_not_found",
+///   "title": "User 42 not found",
+///   "status": 404,
+///   "detail": "账号已注销",
+///   "code": 4204,
+///   "user_id": 42
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ProblemDetailsFormatter {
+    /// 覆盖配置中的 `type_base_uri`
+    type_base_uri: Option<String>,
+}
+
+impl ProblemDetailsFormatter {
+    /// 使用配置中的 `type_base_uri`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 指定 `type` URI 的前缀（覆盖配置中的 `type_base_uri`）
+    pub fn with_type_base_uri(mut self, uri: impl Into<String>) -> Self {
+        self.type_base_uri = Some(uri.into());
+        self
+    }
+
+    /// 拼接 `type` URI
+    fn type_uri(&self, error: &ErrorInfo<'_>) -> String {
+        let base = self.type_base_uri.as_deref().or(error.type_base_uri);
+        match base {
+            Some(base) if !error.key.is_empty() => {
+                if base.ends_with(['/', '#', ':']) {
+                    format!("{}{}", base, error.key)
+                } else {
+                    format!("{}/{}", base, error.key)
+                }
+            }
+            _ => "about:blank".to_string(),
+        }
+    }
+}
+
+impl ResponseFormatter for ProblemDetailsFormatter {
+    fn format(&self, error: &ErrorInfo<'_>) -> Value {
+        let mut doc = serde_json::Map::new();
+        doc.insert("type".into(), self.type_uri(error).into());
+        doc.insert("title".into(), error.message.as_ref().into());
+        doc.insert("status".into(), error.status.into());
+        if let Some(detail) = error.custom_msg {
+            doc.insert("detail".into(), detail.into());
+        }
+        doc.insert("code".into(), error.code.into());
+
+        match error.data {
+            Some(Value::Object(members)) => {
+                for (name, value) in members {
+                    doc.entry(name.as_str()).or_insert_with(|| value.clone());
+                }
+            }
+            Some(data) => {
+                doc.insert("data".into(), data.clone());
+            }
+            None => {}
+        }
+        Value::Object(doc)
+    }
+
+    fn content_type(&self) -> &'static str {
+        "application/problem+json"
+    }
+}
+
+/// 全局响应格式，未设置时使用 `DefaultFormatter`
+static GLOBAL_FORMATTER: RwLock<Option<Arc<dyn ResponseFormatter>>> = RwLock::new(None);
+
+/// 设置全局响应格式
+///
+/// 对之后所有 `AppError` 转换的响应生效；安装了 `FormatResponseLayer` 的路由以路由的设置为准。
+///
+/// # Examples
+///
+/// ```
+/// use biz_error::{ErrorInfo, set_response_formatter};
+/// use serde_json::json;
+///
+/// set_response_formatter(|error: &ErrorInfo<'_>| {
+///     json!({ "error": { "code": error.code, "message": error.msg(), "details": error.data } })
+/// });
+/// ```
+pub fn set_response_formatter(formatter: impl ResponseFormatter) {
+    let mut global = GLOBAL_FORMATTER.write().unwrap_or_else(|e| e.into_inner());
+    *global = Some(Arc::new(formatter));
+}
+
+/// 获取当前的全局响应格式
+pub fn response_formatter() -> Arc<dyn ResponseFormatter> {
+    let global = GLOBAL_FORMATTER.read().unwrap_or_else(|e| e.into_inner());
+    match *global {
+        Some(ref formatter) => Arc::clone(formatter),
+        None => Arc::new(DefaultFormatter),
+    }
+}
@@ -0,0 +1,225 @@
+// 📦 语言协商 - 解析 Accept-Language 并匹配支持的语言
+//
+// 与 Web 框架无关，axum 集成（`NegotiateLanguageLayer`）基于此模块实现。
+//
+// 匹配顺序（对每个请求的语言标签，按 q 值从高到低）：
+// 1. 精确匹配（不区分大小写）：`zh-cn` → `zh-CN`
+// 2. 配置中的回退链（`fallbacks:`），按子标签逐级截断查找：`zh-HK` → `[zh-TW, zh-CN]`
+// 3. 内置别名链：`zh-Hant` / `zh-HK` / `zh-MO` → `zh-TW`，`zh-Hans` / `zh-SG` → `zh-CN`
+// 4. 逐级截断子标签：`en-GB` → `en`，`zh-Hant-TW` → `zh-Hant` → `zh`
+// 5. 同一主语言的其他地区：`zh` → `zh-CN`，`en-GB` → `en-US`
+
+/// 语言回退链表（请求的语言 -> 依次尝试的语言），对应配置中的 `fallbacks:`
+pub type Fallbacks = &'static [(&'static str, &'static [&'static str])];
+
+/// 解析 `Accept-Language` 请求头
+///
+/// 返回按 q 值从高到低排序的语言标签（q 值相同时保持原顺序），忽略 `q=0` 的项。
+///
+/// # Examples
+///
+/// ```
+/// use biz_error::lang::parse_accept_language;
+///
+/// let langs = parse_accept_language("en;q=0.8, zh-CN, *;q=0.1");
+/// assert_eq!(langs, vec![("zh-CN", 1.0), ("en", 0.8), ("*", 0.1)]);
+/// ```
+pub fn parse_accept_language(header: &str) -> Vec<(&str, f32)> {
+    let mut langs: Vec<(&str, f32)> = header
+        .split(',')
+        .filter_map(|item| {
+            let mut parts = item.split(';');
+            let tag = parts.next()?.trim();
+            if tag.is_empty() {
+                return None;
+            }
+            let q = parts
+                .find_map(|param| {
+                    let (key, value) = param.split_once('=')?;
+                    if key.trim() == "q" {
+                        value.trim().parse::<f32>().ok()
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(1.0);
+            (q > 0.0).then_some((tag, q))
+        })
+        .collect();
+
+    langs.sort_by(|a, b| b.1.total_cmp(&a.1));
+    langs
+}
+
+/// 将单个语言标签匹配到可用的语言（先查配置的回退链，再按内置规则匹配）
+///
+/// # Examples
+///
+/// ```
+/// use biz_error::lang::resolve;
+///
+/// let available = &["en", "zh-CN", "zh-TW"];
+/// let fallbacks: biz_error::lang::Fallbacks = &[("zh-HK", &["zh-TW", "zh-CN"]), ("ja", &["zh-TW"])];
+/// assert_eq!(resolve("zh-HK", available, fallbacks), Some("zh-TW"));
+/// assert_eq!(resolve("ja-JP", available, fallbacks), Some("zh-TW"));
+/// assert_eq!(resolve("en-GB", available, fallbacks), Some("en"));
+/// assert_eq!(resolve("fr", available, fallbacks), None);
+/// ```
+pub fn resolve(
+    requested: &str,
+    available: &[&'static str],
+    fallbacks: &[(&str, &[&'static str])],
+) -> Option<&'static str> {
+    let find = |tag: &str| {
+        available
+            .iter()
+            .copied()
+            .find(|lang| lang.eq_ignore_ascii_case(tag))
+    };
+
+    if let Some(lang) = find(requested) {
+        return Some(lang);
+    }
+
+    // 配置的回退链：`zh-HK-x` 依次查找 `zh-HK-x`、`zh-HK`、`zh` 的回退链
+    let mut tag = Some(requested);
+    while let Some(current) = tag {
+        let chain = fallbacks
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(current))
+            .map(|(_, chain)| *chain);
+        if let Some(lang) = chain.and_then(|chain| chain.iter().find_map(|lang| find(lang))) {
+            return Some(lang);
+        }
+        tag = current.rsplit_once('-').map(|(prefix, _)| prefix);
+    }
+
+    match_language(requested, available)
+}
+
+/// 将单个语言标签匹配到支持的语言（仅使用内置规则）
+///
+/// # Examples
+///
+/// ```
+/// use biz_error::lang::match_language;
+///
+/// let supported = &["en", "zh-CN", "zh-TW"];
+/// assert_eq!(match_language("zh-Hant", supported), Some("zh-TW"));
+/// assert_eq!(match_language("en-GB", supported), Some("en"));
+/// assert_eq!(match_language("zh", supported), Some("zh-CN"));
+/// assert_eq!(match_language("fr", supported), None);
+/// ```
+pub fn match_language(requested: &str, supported: &[&'static str]) -> Option<&'static str> {
+    let find = |tag: &str| {
+        supported
+            .iter()
+            .copied()
+            .find(|lang| lang.eq_ignore_ascii_case(tag))
+    };
+
+    // 1. 精确匹配
+    if let Some(lang) = find(requested) {
+        return Some(lang);
+    }
+
+    // 2. 内置别名链
+    if let Some(lang) = aliases(requested).iter().find_map(|alias| find(alias)) {
+        return Some(lang);
+    }
+
+    // 3. 逐级截断子标签
+    let mut tag = requested;
+    while let Some((prefix, _)) = tag.rsplit_once('-') {
+        tag = prefix;
+        if let Some(lang) = find(tag) {
+            return Some(lang);
+        }
+    }
+
+    // 4. 同一主语言的其他地区
+    supported.iter().copied().find(|lang| {
+        lang.split('-')
+            .next()
+            .is_some_and(|primary| primary.eq_ignore_ascii_case(tag))
+    })
+}
+
+/// 根据 `Accept-Language` 请求头协商语言
+///
+/// 没有任何可匹配的语言（或请求头为 `*`）时返回 `default`。
+///
+/// # Examples
+///
+/// ```
+/// use biz_error::lang::negotiate;
+///
+/// let supported = &["en", "zh-CN", "zh-TW"];
+/// assert_eq!(negotiate("fr, zh-HK;q=0.9, en;q=0.8", supported, &[], "en"), "zh-TW");
+/// assert_eq!(negotiate("fr", supported, &[], "en"), "en");
+/// ```
+pub fn negotiate(
+    header: &str,
+    supported: &[&'static str],
+    fallbacks: &[(&str, &[&'static str])],
+    default: &'static str,
+) -> &'static str {
+    for (tag, _) in parse_accept_language(header) {
+        if tag == "*" {
+            return default;
+        }
+        if let Some(lang) = resolve(tag, supported, fallbacks) {
+            return lang;
+        }
+    }
+    default
+}
+
+/// 在运行时消息来源（消息目录、`MessageProvider`）中依次查找的语言
+///
+/// 运行时来源可以提供编译时没有的语言，所以先查请求的语言本身，再查配置的回退链（按子标签逐级截断）、
+/// 截断后的子标签，最后才是编译时消息实际使用的语言 `resolved`。
+pub(crate) fn lookup_order<'a>(
+    requested: &'a str,
+    fallbacks: &[(&str, &[&'static str])],
+    resolved: &'static str,
+) -> Vec<&'a str> {
+    let mut order: Vec<&'a str> = Vec::new();
+    let mut push = |lang: &'a str| {
+        if !lang.is_empty() && !order.iter().any(|l| l.eq_ignore_ascii_case(lang)) {
+            order.push(lang);
+        }
+    };
+
+    push(requested);
+    let mut tag = Some(requested);
+    while let Some(current) = tag {
+        let chain = fallbacks
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(current))
+            .map_or(&[][..], |(_, chain)| *chain);
+        chain.iter().for_each(|lang| push(lang));
+        tag = current.rsplit_once('-').map(|(prefix, _)| prefix);
+    }
+    let mut tag = requested;
+    while let Some((prefix, _)) = tag.rsplit_once('-') {
+        tag = prefix;
+        push(tag);
+    }
+    push(resolved);
+    order
+}
+
+/// 内置的中文脚本/地区别名链
+fn aliases(tag: &str) -> &'static [&'static str] {
+    let lower = tag.to_ascii_lowercase();
+    let is = |prefix: &str| lower == prefix || lower.starts_with(&format!("{}-", prefix));
+
+    if is("zh-hant") || is("zh-tw") || is("zh-hk") || is("zh-mo") {
+        &["zh-TW", "zh-HK", "zh-Hant"]
+    } else if is("zh-hans") || is("zh-cn") || is("zh-sg") {
+        &["zh-CN", "zh-SG", "zh-Hans"]
+    } else {
+        &[]
+    }
+}
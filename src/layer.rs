@@ -0,0 +1,291 @@
+// 📦 axum 中间件 - 语言协商和响应格式
+//
+// `AppError` 转换为响应时会附带一个可重新渲染的 `LocalizedError`（记录语言和响应格式），
+// 中间件在响应返回时修改其中一项并重新渲染响应体，因此两个中间件的顺序无关紧要：
+// - `NegotiateLanguageLayer` 根据请求头 `Accept-Language` 协商出语言，
+//   并以 `Lang` 的形式放入请求扩展，handler 可直接提取
+// - `FormatResponseLayer` 为路由指定响应格式（覆盖全局设置；嵌套时最内层的生效）
+//
+// ```text
+// 请求 ──> [Accept-Language → Lang] ──> handler ──> AppError::into_response (默认语言、全局格式)
+//                                                         │
+// 响应 <── [按 Lang / 路由格式重新渲染响应体] <────────────┘
+// ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use axum::body::Body;
+use axum::extract::{FromRequestParts, Request};
+use axum::http::request::Parts;
+use axum::http::{HeaderValue, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use serde_json::Value;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::ErrorCode;
+use crate::ResponseFormatter;
+use crate::lang::{self, Fallbacks};
+
+/// 协商出的语言
+///
+/// 需要配合 `NegotiateLanguageLayer` 使用，可在 handler 中直接提取：
+///
+/// ```rust,ignore
+/// use biz_error::Lang;
+///
+/// async fn handler(Lang(lang): Lang) -> String {
+///     ErrorCode::NotFound.message_lang(lang).to_string()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lang(pub &'static str);
+
+impl<S: Send + Sync> FromRequestParts<S> for Lang {
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts.extensions.get::<Lang>().copied().ok_or((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Missing language: NegotiateLanguageLayer is not installed",
+        ))
+    }
+}
+
+/// 渲染响应体：语言 + 响应格式 -> 响应体
+type Render = dyn Fn(&'static str, &dyn ResponseFormatter) -> Value + Send + Sync;
+
+/// 可重新渲染的错误响应（由 `AppError::into_response` 放入响应扩展）
+#[derive(Clone)]
+pub(crate) struct LocalizedError {
+    /// 渲染响应时使用的语言
+    lang: &'static str,
+    /// 渲染响应时使用的格式
+    formatter: Arc<dyn ResponseFormatter>,
+    /// 是否已由 `FormatResponseLayer` 指定格式（响应由内向外经过中间件，最内层的路由格式优先）
+    formatter_overridden: bool,
+    /// 渲染响应体
+    render: Arc<Render>,
+}
+
+impl LocalizedError {
+    /// 修改渲染参数（`update` 返回是否有变化），有变化时重新渲染响应体
+    fn rerender(response: &mut Response, update: impl FnOnce(&mut LocalizedError) -> bool) {
+        let Some(localized) = response.extensions_mut().get_mut::<LocalizedError>() else {
+            return;
+        };
+        if !update(localized) {
+            return;
+        }
+        let body = (localized.render)(localized.lang, &*localized.formatter);
+        let content_type = localized.formatter.content_type();
+        // 只替换响应体，保留内层中间件设置的状态码和其他响应头
+        write_body(response, content_type, &body);
+    }
+}
+
+/// 根据 `Accept-Language` 协商语言的中间件
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use axum::{Router, routing::get};
+/// use biz_error::NegotiateLanguageLayer;
+/// use crate::error_codes::ErrorCode;
+///
+/// let app: Router = Router::new()
+///     .route("/users/{id}", get(get_user))
+///     .layer(NegotiateLanguageLayer::for_error_code::<ErrorCode>());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct NegotiateLanguageLayer {
+    supported: &'static [&'static str],
+    fallbacks: Fallbacks,
+    default: &'static str,
+}
+
+impl NegotiateLanguageLayer {
+    /// 指定支持的语言列表和默认语言
+    pub fn new(supported: &'static [&'static str], default: &'static str) -> Self {
+        Self {
+            supported,
+            fallbacks: &[],
+            default,
+        }
+    }
+
+    /// 设置语言回退链
+    pub fn with_fallbacks(mut self, fallbacks: Fallbacks) -> Self {
+        self.fallbacks = fallbacks;
+        self
+    }
+
+    /// 使用错误码枚举生成的语言配置（支持的语言、回退链和默认语言）
+    pub fn for_error_code<E: ErrorCode>() -> Self {
+        Self::new(E::supported_languages(), E::default_language()).with_fallbacks(E::fallbacks())
+    }
+}
+
+impl<S> Layer<S> for NegotiateLanguageLayer {
+    type Service = NegotiateLanguage<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        NegotiateLanguage {
+            inner,
+            layer: *self,
+        }
+    }
+}
+
+/// `NegotiateLanguageLayer` 生成的中间件服务
+#[derive(Debug, Clone)]
+pub struct NegotiateLanguage<S> {
+    inner: S,
+    layer: NegotiateLanguageLayer,
+}
+
+impl<S> Service<Request> for NegotiateLanguage<S>
+where
+    S: Service<Request, Response = Response>,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request) -> Self::Future {
+        let lang = req
+            .headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| {
+                let layer = &self.layer;
+                lang::negotiate(value, layer.supported, layer.fallbacks, layer.default)
+            })
+            .unwrap_or(self.layer.default);
+        req.extensions_mut().insert(Lang(lang));
+
+        let future = self.inner.call(req);
+        Box::pin(async move {
+            let mut response = future.await?;
+            LocalizedError::rerender(&mut response, |localized| {
+                std::mem::replace(&mut localized.lang, lang) != lang
+            });
+            Ok(response)
+        })
+    }
+}
+
+/// 为路由指定错误响应格式的中间件（覆盖 `set_response_formatter` 的全局设置）
+///
+/// 多个 `FormatResponseLayer` 嵌套时离 handler 最近的生效：
+/// 子路由自己的格式不会被外层（如整个应用上）的 `FormatResponseLayer` 覆盖。
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use axum::{Router, routing::get};
+/// use biz_error::{ErrorInfo, FormatResponseLayer};
+/// use serde_json::json;
+///
+/// let app: Router = Router::new()
+///     .route("/users/{id}", get(get_user))
+///     .layer(FormatResponseLayer::new(|error: &ErrorInfo<'_>| {
+///         json!({ "errcode": error.code, "errmsg": error.msg() })
+///     }));
+/// ```
+#[derive(Clone)]
+pub struct FormatResponseLayer {
+    formatter: Arc<dyn ResponseFormatter>,
+}
+
+impl FormatResponseLayer {
+    /// 指定响应格式
+    pub fn new(formatter: impl ResponseFormatter) -> Self {
+        Self {
+            formatter: Arc::new(formatter),
+        }
+    }
+}
+
+impl<S> Layer<S> for FormatResponseLayer {
+    type Service = FormatResponse<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        FormatResponse {
+            inner,
+            formatter: Arc::clone(&self.formatter),
+        }
+    }
+}
+
+/// `FormatResponseLayer` 生成的中间件服务
+#[derive(Clone)]
+pub struct FormatResponse<S> {
+    inner: S,
+    formatter: Arc<dyn ResponseFormatter>,
+}
+
+impl<S> Service<Request> for FormatResponse<S>
+where
+    S: Service<Request, Response = Response>,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let formatter = Arc::clone(&self.formatter);
+        let future = self.inner.call(req);
+        Box::pin(async move {
+            let mut response = future.await?;
+            LocalizedError::rerender(&mut response, |localized| {
+                if localized.formatter_overridden {
+                    return false;
+                }
+                localized.formatter = formatter;
+                localized.formatter_overridden = true;
+                true
+            });
+            Ok(response)
+        })
+    }
+}
+
+/// 渲染错误响应，并将 `LocalizedError` 附加到响应上供中间件重新渲染
+pub(crate) fn localized_response(
+    status: StatusCode,
+    lang: &'static str,
+    formatter: Arc<dyn ResponseFormatter>,
+    render: impl Fn(&'static str, &dyn ResponseFormatter) -> Value + Send + Sync + 'static,
+) -> Response {
+    let mut response = status.into_response();
+    write_body(&mut response, formatter.content_type(), &render(lang, &*formatter));
+    response.extensions_mut().insert(LocalizedError {
+        lang,
+        formatter,
+        formatter_overridden: false,
+        render: Arc::new(render),
+    });
+    response
+}
+
+/// 写入 JSON 响应体和 `Content-Type`
+fn write_body(response: &mut Response, content_type: &'static str, body: &Value) {
+    let headers = response.headers_mut();
+    headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(content_type));
+    headers.remove(header::CONTENT_LENGTH);
+    *response.body_mut() = Body::from(body.to_string());
+}
@@ -78,18 +78,79 @@
 #[cfg(feature = "codegen")]
 pub mod codegen;
 
+// 消息模板渲染
+mod template;
+
+pub use template::render_template;
+
+// 语言协商（解析 Accept-Language）
+pub mod lang;
+
+// 便捷宏（biz_bail! / biz_ensure!）
+mod macros;
+
+// Result / Option 扩展 trait
+mod ext;
+
+pub use ext::{OptionExt, ResultExt};
+
+// 响应格式（可插拔的错误响应体）
+mod format;
+
+pub use format::{
+    DefaultFormatter, ErrorInfo, ProblemDetailsFormatter, ResponseFormatter, response_formatter,
+    set_response_formatter,
+};
+
+// 消息提供者（从数据库等外部来源加载错误消息）
+mod provider;
+
+pub use provider::{
+    CachedMessageProvider, MessageProvider, clear_message_provider, message_provider,
+    set_message_provider,
+};
+
+#[cfg(feature = "sqlite")]
+pub use provider::SqliteMessageProvider;
+
+// Fluent 消息渲染
+#[cfg(feature = "fluent")]
+mod fluent;
+
+// 运行时消息目录（覆盖编译时生成的消息）
+#[cfg(feature = "message-catalog")]
+mod catalog;
+
+#[cfg(feature = "message-catalog")]
+pub use catalog::{
+    CatalogError, CatalogWatcher, MessageCatalog, clear_message_catalog, message_catalog,
+    set_message_catalog, watch_message_catalog,
+};
+
+// axum 语言协商中间件
+#[cfg(feature = "axum")]
+mod layer;
+
+#[cfg(feature = "axum")]
+pub use layer::{FormatResponse, FormatResponseLayer, Lang, NegotiateLanguage, NegotiateLanguageLayer};
+
+#[cfg(feature = "codegen")]
+pub use codegen::generate_error_codes;
+
 // 重新导出过程宏（推荐方式，不需要 build.rs）
 #[cfg(feature = "codegen")]
-pub use biz_error_macros::generate_error_codes;
+pub use biz_error_macros::{BizErrorCode, generate_error_codes};
 
 use serde::Serialize;
 use serde_json::Value;
+use std::borrow::Cow;
+use std::error::Error as StdError;
 use std::fmt;
+use std::sync::Arc;
 
 #[cfg(feature = "axum")]
 use axum::{
     response::{IntoResponse, Response},
-    Json,
     http::StatusCode,
 };
 
@@ -104,15 +165,68 @@ pub trait ErrorCode: Copy + Clone + std::fmt::Debug + PartialEq + Eq + Send + Sy
     /// 获取数字错误码
     fn code(&self) -> i32;
 
+    /// 获取配置中的错误名（snake_case，如 `user_not_found`）
+    ///
+    /// 生成的代码会覆盖此实现；手写实现未覆盖时为空字符串。
+    fn key(&self) -> &'static str {
+        ""
+    }
+
     /// 获取错误消息（默认语言）
     fn message(&self) -> &'static str;
 
     /// 获取指定语言的错误消息
+    ///
+    /// 请求的语言按 `resolve_lang` 的规则匹配，例如 `zh` 可以匹配到 `zh-CN`。
     fn message_lang(&self, lang: &str) -> &'static str;
 
-    /// 获取 HTTP 状态码（需要 axum feature）
+    /// 解析请求的语言实际会使用哪种语言的消息
+    ///
+    /// 依次尝试精确匹配、配置的回退链（`fallbacks:`）、BCP 47 前缀匹配，都不匹配时返回默认语言。
+    /// 生成的代码会按每个错误实际拥有的翻译覆盖此实现。
+    fn resolve_lang(&self, lang: &str) -> &'static str {
+        lang::resolve(lang, Self::supported_languages(), Self::fallbacks())
+            .unwrap_or_else(Self::default_language)
+    }
+
+    /// 支持的语言列表（用于语言协商，生成的代码会覆盖此实现）
+    fn supported_languages() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// 默认语言（生成的代码会覆盖此实现）
+    fn default_language() -> &'static str {
+        "en"
+    }
+
+    /// 语言回退链（对应配置中的 `fallbacks:`，生成的代码会覆盖此实现）
+    fn fallbacks() -> lang::Fallbacks {
+        &[]
+    }
+
+    /// Problem Details `type` URI 的前缀（对应配置中的 `type_base_uri`，生成的代码会覆盖此实现）
+    fn type_base_uri() -> Option<&'static str> {
+        None
+    }
+
+    /// 指定语言的 Fluent 资源（`.ftl` 内容，对应配置中的 `fluent:`，生成的代码会覆盖此实现）
+    ///
+    /// 启用 `fluent` feature 时，`AppError` 用它渲染该语言下来自 Fluent 的消息（按复数规则等选择变体），
+    /// 否则使用 `message_lang` 返回的扁平模板（选择表达式的默认变体）。
+    fn fluent_resource(_lang: &str) -> Option<&'static str> {
+        None
+    }
+
+    /// 获取 HTTP 状态码（100-599，与 Web 框架无关）
+    fn http_status_code(&self) -> u16;
+
+    /// 获取 axum 的 HTTP 状态码（需要 axum feature）
+    ///
+    /// 由 `http_status_code` 转换，状态码无效时为 500。
     #[cfg(feature = "axum")]
-    fn http_status(&self) -> StatusCode;
+    fn http_status(&self) -> StatusCode {
+        StatusCode::from_u16(self.http_status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
 }
 
 // ============================================
@@ -183,7 +297,9 @@ impl ErrorResponse {
 /// # 核心特性
 /// - **code**: 错误码（通过 ErrorCode 枚举获取）
 /// - **msg**: 错误消息（支持自定义覆盖默认消息）
+/// - **args**: 消息模板参数（渲染 `{name}` 占位符，任何语言都适用）
 /// - **data**: 可选的业务数据（携带错误上下文）
+/// - **source**: 可选的底层错误（只用于日志和排查，不会出现在响应中）
 ///
 /// # 设计理念
 ///
@@ -221,6 +337,29 @@ impl ErrorResponse {
 ///     .with_data(json!({ "field": "user_id" }));
 /// ```
 ///
+/// ## 消息模板参数
+///
+/// 配置中的消息可以包含 `{name}` 占位符，生成器会为其生成类型化的构造函数：
+///
+/// ```rust,ignore
+/// // user_not_found:
+/// //   message:
+/// //     en: "User {user_id} not found"
+/// //     zh-CN: "用户 {user_id} 不存在"
+/// let error = ErrorCode::user_not_found(42);
+/// assert_eq!(error.msg(), "User 42 not found");
+/// assert_eq!(error.msg_lang("zh-CN"), "用户 42 不存在");
+/// ```
+///
+/// ## 保留底层错误
+///
+/// ```rust,ignore
+/// let user = sqlx::query_as::<_, User>(SQL)
+///     .fetch_one(&pool)
+///     .await
+///     .map_err(|e| AppError::new(ErrorCode::DbError).with_source(e))?;
+/// ```
+///
 /// ## 自定义业务错误类型
 ///
 /// ```rust,ignore
@@ -245,8 +384,12 @@ pub struct AppError<E: ErrorCode> {
     error_code: E,
     /// 自定义消息（覆盖默认消息）
     custom_msg: Option<String>,
+    /// 消息模板参数
+    args: Vec<(&'static str, String)>,
     /// 附加数据
     data: Option<Value>,
+    /// 底层错误（使用 Arc 以保持 AppError 可 Clone）
+    source: Option<Arc<dyn StdError + Send + Sync>>,
 }
 
 impl<E: ErrorCode> AppError<E> {
@@ -264,7 +407,9 @@ impl<E: ErrorCode> AppError<E> {
         Self {
             error_code,
             custom_msg: None,
+            args: Vec::new(),
             data: None,
+            source: None,
         }
     }
 
@@ -272,8 +417,9 @@ impl<E: ErrorCode> AppError<E> {
     ///
     /// # Examples
     ///
-    /// ```
-    /// use biz_error::{AppError, ErrorCode};
+    /// ```rust,ignore
+    /// use biz_error::AppError;
+    /// use crate::error_codes::ErrorCode;
     ///
     /// let error = AppError::new(ErrorCode::InvalidParam)
     ///     .with_msg("用户ID不能为空");
@@ -283,12 +429,36 @@ impl<E: ErrorCode> AppError<E> {
         self
     }
 
-    /// 设置附加数据
+    /// 设置消息模板参数
+    ///
+    /// 用于渲染消息中的 `{name}` 占位符，对所有语言的消息都生效。
+    /// 通常不需要直接调用，而是使用生成的构造函数（如 `ErrorCode::user_not_found(id)`）。
     ///
     /// # Examples
     ///
+    /// ```rust,ignore
+    /// use biz_error::AppError;
+    /// use crate::error_codes::ErrorCode;
+    ///
+    /// let error = AppError::new(ErrorCode::UserNotFound)
+    ///     .with_arg("user_id", 42);
     /// ```
-    /// use biz_error::{AppError, ErrorCode};
+    pub fn with_arg(mut self, name: &'static str, value: impl fmt::Display) -> Self {
+        let value = value.to_string();
+        match self.args.iter_mut().find(|(key, _)| *key == name) {
+            Some(arg) => arg.1 = value,
+            None => self.args.push((name, value)),
+        }
+        self
+    }
+
+    /// 设置附加数据
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use biz_error::AppError;
+    /// use crate::error_codes::ErrorCode;
     /// use serde_json::json;
     ///
     /// let error = AppError::new(ErrorCode::InvalidParam)
@@ -299,6 +469,55 @@ impl<E: ErrorCode> AppError<E> {
         self
     }
 
+    /// 设置底层错误
+    ///
+    /// 底层错误通过 `std::error::Error::source` 暴露，并包含在 `Debug` 输出和
+    /// `{:#}` 格式的 `Display` 输出中，但不会出现在返回给客户端的响应中。
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use biz_error::AppError;
+    /// use crate::error_codes::ErrorCode;
+    ///
+    /// let error = std::fs::read_to_string("config.toml")
+    ///     .map_err(|e| AppError::new(ErrorCode::InternalError).with_source(e));
+    /// ```
+    pub fn with_source(mut self, source: impl Into<Box<dyn StdError + Send + Sync>>) -> Self {
+        self.source = Some(Arc::from(source.into()));
+        self
+    }
+
+    /// 转换为另一种错误码类型的 `AppError`，消息、参数、数据和底层错误保持不变
+    ///
+    /// 用于配置了 `groups` 时，将分组的错误（如 `AppError<UserError>`）转换为汇总的 `AppError<ErrorCode>`。
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use biz_error::AppError;
+    /// use crate::error_codes::{ErrorCode, UserError};
+    ///
+    /// fn find_user(id: u64) -> Result<User, AppError<UserError>> { ... }
+    ///
+    /// async fn handler(id: u64) -> Result<Json<User>, AppError<ErrorCode>> {
+    ///     let user = find_user(id).map_err(AppError::into_code)?;
+    ///     Ok(Json(user))
+    /// }
+    /// ```
+    pub fn into_code<F>(self) -> AppError<F>
+    where
+        F: ErrorCode + From<E>,
+    {
+        AppError {
+            error_code: F::from(self.error_code),
+            custom_msg: self.custom_msg,
+            args: self.args,
+            data: self.data,
+            source: self.source,
+        }
+    }
+
     /// 获取错误码枚举
     pub fn error_code(&self) -> E {
         self.error_code
@@ -309,11 +528,51 @@ impl<E: ErrorCode> AppError<E> {
         self.error_code.code()
     }
 
-    /// 获取错误消息
-    pub fn msg(&self) -> &str {
-        self.custom_msg
-            .as_deref()
-            .unwrap_or_else(|| self.error_code.message())
+    /// 获取错误消息（默认语言，已渲染模板参数）
+    pub fn msg(&self) -> Cow<'_, str> {
+        match self.custom_msg {
+            Some(ref msg) => Cow::Borrowed(msg),
+            None => self.render(E::default_language()),
+        }
+    }
+
+    /// 获取指定语言的错误消息（已渲染模板参数）
+    ///
+    /// 设置了自定义消息时始终返回自定义消息。
+    pub fn msg_lang(&self, lang: &str) -> Cow<'_, str> {
+        match self.custom_msg {
+            Some(ref msg) => Cow::Borrowed(msg),
+            None => self.render(lang),
+        }
+    }
+
+    /// 渲染错误码在指定语言下的消息
+    ///
+    /// 依次使用全局消息目录（`message-catalog` feature）、全局 `MessageProvider` 中的模板，
+    /// 都没有时使用编译时生成的消息（`fluent` feature 启用时来自 Fluent 的消息由 Fluent 渲染）。
+    /// 这两个运行时来源可以提供编译时没有的语言，按 `lang::lookup_order` 先查请求的语言及其回退链。
+    fn render(&self, requested: &str) -> Cow<'_, str> {
+        let lang = self.error_code.resolve_lang(requested);
+        let order = lang::lookup_order(requested, E::fallbacks(), lang);
+        #[cfg(feature = "message-catalog")]
+        if let Some(template) = order.iter().find_map(|l| catalog::lookup(self.error_code.key(), l)) {
+            return Cow::Owned(render_template(&template, &self.args).into_owned());
+        }
+        if let Some(template) = order.iter().find_map(|l| provider::lookup(self.error_code.code(), l)) {
+            return Cow::Owned(render_template(&template, &self.args).into_owned());
+        }
+        #[cfg(feature = "fluent")]
+        if let Some(resource) = E::fluent_resource(lang)
+            && let Some(message) = fluent::format(resource, lang, self.error_code.key(), &self.args)
+        {
+            return Cow::Owned(message);
+        }
+        render_template(self.error_code.message_lang(lang), &self.args)
+    }
+
+    /// 获取消息模板参数
+    pub fn args(&self) -> &[(&'static str, String)] {
+        &self.args
     }
 
     /// 获取附加数据
@@ -321,12 +580,31 @@ impl<E: ErrorCode> AppError<E> {
         self.data.as_ref()
     }
 
+    /// 获取指定语言的错误信息（交给 `ResponseFormatter` 生成响应体）
+    ///
+    /// 请求的语言按 `ErrorCode::resolve_lang` 的规则匹配，`ErrorInfo::lang` 为编译时消息使用的语言。
+    pub fn info_lang(&self, requested: &str) -> ErrorInfo<'_> {
+        ErrorInfo {
+            code: self.error_code.code(),
+            key: self.error_code.key(),
+            type_base_uri: E::type_base_uri(),
+            message: self.render(requested),
+            custom_msg: self.custom_msg.as_deref(),
+            lang: self.error_code.resolve_lang(requested),
+            args: &self.args,
+            data: self.data.as_ref(),
+            status: self.error_code.http_status_code(),
+        }
+    }
+
     /// 转换为 ErrorResponse
     pub fn to_response(&self) -> ErrorResponse {
-        let mut resp = ErrorResponse::from_error_code(self.error_code);
-        if let Some(ref msg) = self.custom_msg {
-            resp = resp.with_msg(msg);
-        }
+        self.to_response_lang(E::default_language())
+    }
+
+    /// 转换为指定语言的 ErrorResponse
+    pub fn to_response_lang(&self, lang: &str) -> ErrorResponse {
+        let mut resp = ErrorResponse::from_error_code(self.error_code).with_msg(self.msg_lang(lang));
         if let Some(ref data) = self.data {
             resp = resp.with_data(data.clone());
         }
@@ -340,12 +618,27 @@ impl<E: ErrorCode> AppError<E> {
 }
 
 impl<E: ErrorCode> fmt::Display for AppError<E> {
+    /// `{}` 只输出错误码和消息；`{:#}` 额外输出底层错误链，便于记录日志
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}] {}", self.code(), self.msg())
+        write!(f, "[{}] {}", self.code(), self.msg())?;
+        if f.alternate() {
+            let mut source = StdError::source(self);
+            while let Some(err) = source {
+                write!(f, ": {}", err)?;
+                source = err.source();
+            }
+        }
+        Ok(())
     }
 }
 
-impl<E: ErrorCode> std::error::Error for AppError<E> {}
+impl<E: ErrorCode> StdError for AppError<E> {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_deref()
+            .map(|err| err as &(dyn StdError + 'static))
+    }
+}
 
 // ============================================
 // From 实现常见错误类型
@@ -358,15 +651,21 @@ impl<E: ErrorCode> From<E> for AppError<E> {
 }
 
 // Note: Removed anyhow::Error conversion because we don't know what error code to use
-// Users should explicitly create AppError with appropriate error code:
-// AppError::new(YourErrorCode::InternalError).with_msg(err.to_string())
+// Users should explicitly create AppError with appropriate error code and keep the cause:
+// AppError::new(YourErrorCode::InternalError).with_source(err)
 
 #[cfg(feature = "axum")]
 impl<E: ErrorCode> IntoResponse for AppError<E> {
     fn into_response(self) -> Response {
+        // 先按默认语言和全局响应格式渲染；
+        // 安装了 NegotiateLanguageLayer / FormatResponseLayer 时会按协商出的语言和路由的格式重新渲染
         let status = self.error_code.http_status();
-        let resp = self.to_response();
-        (status, Json(resp)).into_response()
+        layer::localized_response(
+            status,
+            E::default_language(),
+            response_formatter(),
+            move |lang, formatter| formatter.format(&self.info_lang(lang)),
+        )
     }
 }
 
@@ -0,0 +1,86 @@
+// 📦 便捷宏 - 构造 AppError 并提前返回
+//
+// - `biz_bail!(code, ...)`：直接返回 `Err(AppError)`
+// - `biz_ensure!(cond, code, ...)`：条件不满足时返回 `Err(AppError)`
+//
+// 错误码之后可以跟附加数据（`data = ...`）和格式化消息（与 `format!` 相同），两者都是可选的：
+//
+// ```rust,ignore
+// biz_bail!(ErrorCode::NotFound);
+// biz_bail!(ErrorCode::InvalidParam, "user id {} invalid", id);
+// biz_bail!(ErrorCode::InvalidParam, data = json!({ "field": "id" }));
+// biz_bail!(ErrorCode::InvalidParam, data = json!({ "field": "id" }), "user id {} invalid", id);
+// ```
+//
+// 返回的错误会经过 `From::from` 转换，因此也可以用在返回其他错误类型
+// （实现了 `From<AppError<E>>`）的函数中。
+
+/// 构造 `AppError` 并立即返回 `Err`
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use biz_error::{AppError, biz_bail};
+/// use serde_json::json;
+///
+/// fn check_user_id(id: u64) -> Result<(), AppError<ErrorCode>> {
+///     if id == 0 {
+///         biz_bail!(ErrorCode::InvalidParam, data = json!({ "field": "id" }), "user id {} invalid", id);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[macro_export]
+macro_rules! biz_bail {
+    ($code:expr $(,)?) => {
+        return ::core::result::Result::Err(::core::convert::From::from(
+            $crate::AppError::new($code),
+        ))
+    };
+    ($code:expr, data = $data:expr $(,)?) => {
+        return ::core::result::Result::Err(::core::convert::From::from(
+            $crate::AppError::new($code).with_data($data),
+        ))
+    };
+    ($code:expr, data = $data:expr, $fmt:literal $(, $arg:expr)* $(,)?) => {
+        return ::core::result::Result::Err(::core::convert::From::from(
+            $crate::AppError::new($code)
+                .with_data($data)
+                .with_msg(::std::format!($fmt $(, $arg)*)),
+        ))
+    };
+    ($code:expr, $fmt:literal $(, $arg:expr)* $(,)?) => {
+        return ::core::result::Result::Err(::core::convert::From::from(
+            $crate::AppError::new($code).with_msg(::std::format!($fmt $(, $arg)*)),
+        ))
+    };
+}
+
+/// 条件不满足时构造 `AppError` 并返回 `Err`
+///
+/// 错误码之后的参数与 `biz_bail!` 相同。
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use biz_error::{AppError, biz_ensure};
+/// use serde_json::json;
+///
+/// fn transfer(amount: u64, balance: u64) -> Result<(), AppError<ErrorCode>> {
+///     biz_ensure!(amount > 0, ErrorCode::InvalidParam, "amount must be positive");
+///     biz_ensure!(
+///         amount <= balance,
+///         ErrorCode::InsufficientBalance,
+///         data = json!({ "amount": amount, "balance": balance })
+///     );
+///     Ok(())
+/// }
+/// ```
+#[macro_export]
+macro_rules! biz_ensure {
+    ($cond:expr, $($rest:tt)+) => {
+        if !$cond {
+            $crate::biz_bail!($($rest)+);
+        }
+    };
+}
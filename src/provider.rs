@@ -0,0 +1,296 @@
+// 📦 消息提供者 - 从数据库等外部来源按「错误码 + 语言」提供错误消息
+//
+// 渲染 AppError 消息时的查找顺序：
+//
+// ```text
+// 运行时消息目录 (message-catalog) ──> 全局 MessageProvider ──> 编译时生成的消息 (message_lang)
+// ```
+//
+// 查询数据库通常比渲染消息慢得多，`CachedMessageProvider` 在任意提供者外面加一层带过期时间和容量上限的缓存；
+// 启用 `sqlite` feature 时提供基于 SQLite 的参考实现 `SqliteMessageProvider`。
+
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+/// 错误消息的外部来源
+///
+/// 返回的是消息模板，`{name}` 占位符由 `AppError` 的模板参数渲染；
+/// 返回 `None` 时使用编译时生成的消息。
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use biz_error::MessageProvider;
+///
+/// struct Maintenance;
+///
+/// impl MessageProvider for Maintenance {
+///     fn message(&self, code: i32, _lang: &str) -> Option<Cow<'_, str>> {
+///         (code >= 5000).then_some(Cow::Borrowed("Service under maintenance"))
+///     }
+/// }
+/// ```
+pub trait MessageProvider: Send + Sync + 'static {
+    /// 查找错误码在指定语言下的消息模板
+    ///
+    /// 渲染消息时依次用请求的语言、它的回退链和截断后的子标签查询，最后才是按 `ErrorCode::resolve_lang`
+    /// 解析后的语言，因此提供者可以提供编译时没有的语言；一次渲染可能查询多次，可以用 `CachedMessageProvider` 包装。
+    fn message(&self, code: i32, lang: &str) -> Option<Cow<'_, str>>;
+}
+
+/// 全局消息提供者，未设置时只使用编译时生成的消息
+static GLOBAL_PROVIDER: RwLock<Option<Arc<dyn MessageProvider>>> = RwLock::new(None);
+
+/// 设置全局消息提供者
+///
+/// 对之后渲染的所有 `AppError` 消息生效。
+pub fn set_message_provider(provider: impl MessageProvider) {
+    let mut global = GLOBAL_PROVIDER.write().unwrap_or_else(|e| e.into_inner());
+    *global = Some(Arc::new(provider));
+}
+
+/// 清除全局消息提供者
+pub fn clear_message_provider() {
+    let mut global = GLOBAL_PROVIDER.write().unwrap_or_else(|e| e.into_inner());
+    *global = None;
+}
+
+/// 获取当前的全局消息提供者
+pub fn message_provider() -> Option<Arc<dyn MessageProvider>> {
+    let global = GLOBAL_PROVIDER.read().unwrap_or_else(|e| e.into_inner());
+    global.clone()
+}
+
+/// 向全局消息提供者查询消息模板
+pub(crate) fn lookup(code: i32, lang: &str) -> Option<String> {
+    let provider = message_provider()?;
+    provider.message(code, lang).map(Cow::into_owned)
+}
+
+/// 带缓存的消息提供者
+///
+/// 查询结果（包括「没有这条消息」）缓存 `ttl` 时长，过期后重新查询；
+/// 缓存条数达到 `capacity` 时淘汰最早缓存的条目。
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use std::time::Duration;
+/// use biz_error::{CachedMessageProvider, MessageProvider, set_message_provider};
+///
+/// struct Database;
+///
+/// impl MessageProvider for Database {
+///     fn message(&self, _code: i32, _lang: &str) -> Option<Cow<'_, str>> {
+///         None // 查询数据库
+///     }
+/// }
+///
+/// set_message_provider(CachedMessageProvider::new(Database, Duration::from_secs(60), 1024));
+/// ```
+pub struct CachedMessageProvider<P> {
+    inner: P,
+    ttl: Duration,
+    capacity: usize,
+    cache: Mutex<Cache>,
+}
+
+#[derive(Default)]
+struct Cache {
+    entries: HashMap<(i32, String), CacheEntry>,
+    /// 缓存键按首次缓存的顺序排列，用于淘汰
+    order: VecDeque<(i32, String)>,
+}
+
+struct CacheEntry {
+    message: Option<String>,
+    expires_at: Instant,
+}
+
+impl<P: MessageProvider> CachedMessageProvider<P> {
+    /// 创建带缓存的消息提供者
+    pub fn new(inner: P, ttl: Duration, capacity: usize) -> Self {
+        Self {
+            inner,
+            ttl,
+            capacity,
+            cache: Mutex::new(Cache::default()),
+        }
+    }
+
+    /// 被包装的消息提供者
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// 清空缓存（例如在数据库中修改了消息之后）
+    pub fn clear(&self) {
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        cache.entries.clear();
+        cache.order.clear();
+    }
+
+    /// 当前缓存的条数
+    pub fn cached_len(&self) -> usize {
+        let cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        cache.entries.len()
+    }
+}
+
+impl<P: MessageProvider> MessageProvider for CachedMessageProvider<P> {
+    fn message(&self, code: i32, lang: &str) -> Option<Cow<'_, str>> {
+        if self.capacity == 0 {
+            return self.inner.message(code, lang);
+        }
+
+        let key = (code, lang.to_string());
+        {
+            let cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(entry) = cache.entries.get(&key)
+                && entry.expires_at > Instant::now()
+            {
+                return entry.message.clone().map(Cow::Owned);
+            }
+        }
+
+        // 查询期间不持有锁，慢查询不会阻塞其他语言/错误码的缓存命中
+        let message = self.inner.message(code, lang).map(Cow::into_owned);
+
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = CacheEntry {
+            message: message.clone(),
+            expires_at: Instant::now() + self.ttl,
+        };
+        if let Some(existing) = cache.entries.get_mut(&key) {
+            *existing = entry;
+        } else {
+            while cache.entries.len() >= self.capacity {
+                let Some(oldest) = cache.order.pop_front() else {
+                    break;
+                };
+                cache.entries.remove(&oldest);
+            }
+            cache.order.push_back(key.clone());
+            cache.entries.insert(key, entry);
+        }
+        message.map(Cow::Owned)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteMessageProvider;
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use std::borrow::Cow;
+    use std::path::Path;
+    use std::sync::Mutex;
+
+    use rusqlite::{Connection, OptionalExtension};
+
+    use super::MessageProvider;
+
+    /// 基于 SQLite 的消息提供者（参考实现）
+    ///
+    /// 消息存放在 `biz_error_messages` 表中：
+    ///
+    /// ```sql
+    /// CREATE TABLE biz_error_messages (
+    ///     code    INTEGER NOT NULL,
+    ///     lang    TEXT    NOT NULL,
+    ///     message TEXT    NOT NULL,
+    ///     PRIMARY KEY (code, lang)
+    /// );
+    /// ```
+    ///
+    /// 查询出错时视为没有这条消息（使用编译时生成的消息），不会影响错误响应本身。
+    /// 生产环境中通常与 `CachedMessageProvider` 一起使用。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use biz_error::{MessageProvider, SqliteMessageProvider};
+    ///
+    /// let provider = SqliteMessageProvider::open_in_memory()?;
+    /// provider.create_table()?;
+    /// provider.set_message(4004, "zh-CN", "找不到用户 {user_id}")?;
+    /// assert_eq!(provider.message(4004, "zh-CN").as_deref(), Some("找不到用户 {user_id}"));
+    /// assert_eq!(provider.message(4004, "en"), None);
+    /// # Ok::<(), rusqlite::Error>(())
+    /// ```
+    pub struct SqliteMessageProvider {
+        conn: Mutex<Connection>,
+    }
+
+    impl SqliteMessageProvider {
+        /// 使用已有的数据库连接
+        pub fn new(conn: Connection) -> Self {
+            Self {
+                conn: Mutex::new(conn),
+            }
+        }
+
+        /// 打开数据库文件
+        pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+            Connection::open(path).map(Self::new)
+        }
+
+        /// 打开内存数据库（用于测试）
+        pub fn open_in_memory() -> rusqlite::Result<Self> {
+            Connection::open_in_memory().map(Self::new)
+        }
+
+        /// 创建消息表（已存在时不做任何事）
+        pub fn create_table(&self) -> rusqlite::Result<()> {
+            self.conn().execute_batch(
+                "CREATE TABLE IF NOT EXISTS biz_error_messages (
+                    code    INTEGER NOT NULL,
+                    lang    TEXT    NOT NULL,
+                    message TEXT    NOT NULL,
+                    PRIMARY KEY (code, lang)
+                )",
+            )
+        }
+
+        /// 写入（或替换）一条消息
+        pub fn set_message(&self, code: i32, lang: &str, message: &str) -> rusqlite::Result<()> {
+            self.conn().execute(
+                "INSERT OR REPLACE INTO biz_error_messages (code, lang, message) VALUES (?1, ?2, ?3)",
+                (code, lang, message),
+            )?;
+            Ok(())
+        }
+
+        /// 删除一条消息
+        pub fn remove_message(&self, code: i32, lang: &str) -> rusqlite::Result<()> {
+            self.conn().execute(
+                "DELETE FROM biz_error_messages WHERE code = ?1 AND lang = ?2",
+                (code, lang),
+            )?;
+            Ok(())
+        }
+
+        fn conn(&self) -> std::sync::MutexGuard<'_, Connection> {
+            self.conn.lock().unwrap_or_else(|e| e.into_inner())
+        }
+    }
+
+    impl MessageProvider for SqliteMessageProvider {
+        fn message(&self, code: i32, lang: &str) -> Option<Cow<'_, str>> {
+            self.conn()
+                .query_row(
+                    "SELECT message FROM biz_error_messages WHERE code = ?1 AND lang = ?2",
+                    (code, lang),
+                    |row| row.get::<_, String>(0),
+                )
+                .optional()
+                .ok()
+                .flatten()
+                .map(Cow::Owned)
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// 📦 消息模板 - 渲染错误消息中的 `{name}` 占位符
+//
+// 语法：
+// - `{name}` 替换为同名参数的值，没有对应参数时原样保留
+// - `{{` 和 `}}` 分别转义为字面量 `{` 和 `}`
+//
+// 占位符在编译时已由代码生成器校验（各语言一致、参数齐全），
+// 这里只负责替换。
+
+use std::borrow::Cow;
+
+/// 渲染消息模板
+///
+/// 模板中没有占位符和转义时直接借用原字符串，不产生分配。
+///
+/// # Examples
+///
+/// ```
+/// use biz_error::render_template;
+///
+/// let args = [("user_id", "42".to_string())];
+/// assert_eq!(render_template("用户 {user_id} 不存在", &args), "用户 42 不存在");
+/// assert_eq!(render_template("{{literal}}", &args), "{literal}");
+/// ```
+pub fn render_template<'a>(template: &'a str, args: &[(&str, String)]) -> Cow<'a, str> {
+    if !template.contains(['{', '}']) {
+        return Cow::Borrowed(template);
+    }
+
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(pos) = rest.find(['{', '}']) {
+        out.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            out.push_str(&tail[..1]);
+            rest = &tail[2..];
+        } else if let (true, Some(end)) = (tail.starts_with('{'), tail.find('}')) {
+            let name = &tail[1..end];
+            match args.iter().find(|(key, _)| *key == name) {
+                Some((_, value)) => out.push_str(value),
+                None => out.push_str(&tail[..=end]),
+            }
+            rest = &tail[end + 1..];
+        } else {
+            out.push_str(&tail[..1]);
+            rest = &tail[1..];
+        }
+    }
+
+    out.push_str(rest);
+    Cow::Owned(out)
+}
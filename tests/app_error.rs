@@ -0,0 +1,94 @@
+// 🧪 AppError 行为测试（使用手写的 ErrorCode 实现，不依赖代码生成）
+
+mod common;
+
+use biz_error::{AppError, DefaultFormatter, ErrorCode as _, ProblemDetailsFormatter, ResponseFormatter};
+use serde_json::json;
+use common::ErrorCode;
+
+#[test]
+fn template_args_render_in_every_language() {
+    let error = AppError::new(ErrorCode::UserNotFound).with_arg("user_id", 42);
+    assert_eq!(error.msg(), "User 42 not found");
+    assert_eq!(error.msg_lang("zh-CN"), "用户 42 不存在");
+    assert_eq!(error.to_string(), "[4004] User 42 not found");
+    assert_eq!(error.to_response().msg, "User 42 not found");
+}
+
+#[test]
+fn custom_message_overrides_template() {
+    let error = AppError::new(ErrorCode::UserNotFound)
+        .with_arg("user_id", 42)
+        .with_msg("gone");
+    assert_eq!(error.msg(), "gone");
+    assert_eq!(error.msg_lang("zh-CN"), "gone");
+}
+
+#[test]
+fn message_without_args_is_unchanged() {
+    let error = AppError::new(ErrorCode::InvalidParam);
+    assert_eq!(error.msg(), ErrorCode::InvalidParam.message());
+    assert!(error.args().is_empty());
+}
+
+#[test]
+fn info_resolves_language_and_keeps_custom_message_separate() {
+    let error = AppError::new(ErrorCode::UserNotFound)
+        .with_arg("user_id", 42)
+        .with_msg("custom");
+    let info = error.info_lang("zh");
+    assert_eq!(info.lang, "zh-CN");
+    assert_eq!(info.message, "用户 42 不存在");
+    assert_eq!(info.msg(), "custom");
+    assert_eq!(info.status, 404);
+}
+
+#[test]
+fn default_formatter_matches_error_response() {
+    let error = AppError::new(ErrorCode::InvalidParam).with_data(json!({ "field": "user_id" }));
+    let body = DefaultFormatter.format(&error.info_lang("en"));
+    assert_eq!(body, serde_json::to_value(error.to_response()).unwrap());
+    assert_eq!(
+        body,
+        json!({ "code": 4000, "msg": "INVALID PARAMETER", "data": { "field": "user_id" } })
+    );
+}
+
+#[test]
+fn problem_details_detail_and_extension_members() {
+    let formatter = ProblemDetailsFormatter::new().with_type_base_uri("urn:errors:");
+    let error = AppError::new(ErrorCode::InvalidParam)
+        .with_msg("user_id must be positive")
+        .with_data(json!({ "field": "user_id", "status": "ignored" }));
+
+    assert_eq!(
+        formatter.format(&error.info_lang("en")),
+        json!({
+            "type": "urn:errors:invalid_param",
+            "title": "INVALID PARAMETER",
+            "status": 400,
+            "detail": "user_id must be positive",
+            "code": 4000,
+            "field": "user_id"
+        })
+    );
+
+    let error = AppError::new(ErrorCode::InvalidParam).with_data(json!(["a", "b"]));
+    assert_eq!(formatter.format(&error.info_lang("en"))["data"], json!(["a", "b"]));
+}
+
+#[test]
+fn source_is_chained_but_not_exposed_in_response() {
+    let io = std::io::Error::new(std::io::ErrorKind::NotFound, "users.db missing");
+    let error = AppError::new(ErrorCode::InvalidParam).with_source(io);
+
+    let source = std::error::Error::source(&error).unwrap();
+    assert_eq!(source.to_string(), "users.db missing");
+    assert!(format!("{:?}", error).contains("users.db missing"));
+    assert_eq!(error.to_string(), "[4000] INVALID PARAMETER");
+    assert_eq!(format!("{:#}", error), "[4000] INVALID PARAMETER: users.db missing");
+
+    let response = serde_json::to_string(&error.to_response()).unwrap();
+    assert!(!response.contains("users.db"));
+    assert!(std::error::Error::source(&error.clone()).is_some());
+}
@@ -0,0 +1,124 @@
+// 🧪 运行时消息目录测试（覆盖编译时生成的消息、文件热加载）
+
+#![cfg(feature = "message-catalog")]
+
+mod common;
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use biz_error::{
+    AppError, CatalogError, MessageCatalog, clear_message_catalog, message_catalog,
+    set_message_catalog, watch_message_catalog,
+};
+use common::ErrorCode;
+
+/// 全局消息目录在同一个测试进程内共享，修改它的测试串行执行
+static GLOBAL: Mutex<()> = Mutex::new(());
+
+fn temp_file(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("biz-error-catalog-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join(name)
+}
+
+/// 等待文件监听线程完成重新加载
+fn wait_until(mut done: impl FnMut() -> bool) -> bool {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while Instant::now() < deadline {
+        if done() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    false
+}
+
+#[test]
+fn yaml_and_json_have_the_same_shape() {
+    let yaml = MessageCatalog::from_yaml_str("user_not_found:\n  zh-CN: 找不到用户 {user_id}\n").unwrap();
+    let json = MessageCatalog::from_json_str(r#"{"user_not_found": {"zh-CN": "找不到用户 {user_id}"}}"#)
+        .unwrap();
+    assert_eq!(yaml, json);
+    assert_eq!(yaml.len(), 1);
+    assert!(MessageCatalog::from_yaml_str("").unwrap().is_empty());
+
+    let err = MessageCatalog::from_yaml_str("user_not_found: oops").unwrap_err();
+    assert!(matches!(err, CatalogError::Parse { path: None, .. }), "{:?}", err);
+}
+
+#[test]
+fn catalog_overrides_compiled_messages_and_falls_back() {
+    let _guard = GLOBAL.lock().unwrap_or_else(|e| e.into_inner());
+    set_message_catalog(
+        MessageCatalog::new().with_message("user_not_found", "zh-CN", "找不到用户 {user_id}"),
+    );
+
+    let error = AppError::new(ErrorCode::UserNotFound).with_arg("user_id", 42);
+    assert_eq!(error.msg_lang("zh-CN"), "找不到用户 42");
+    // 请求的语言先按 resolve_lang 解析再查目录
+    assert_eq!(error.info_lang("zh").message, "找不到用户 42");
+    // 目录中没有的语言和错误使用编译时生成的消息
+    assert_eq!(error.msg(), "User 42 not found");
+    assert_eq!(AppError::new(ErrorCode::InvalidParam).msg_lang("zh-CN"), "参数无效");
+    // 自定义消息仍然优先
+    assert_eq!(error.with_msg("custom").msg_lang("zh-CN"), "custom");
+
+    clear_message_catalog();
+    assert!(message_catalog().is_none());
+    let error = AppError::new(ErrorCode::UserNotFound).with_arg("user_id", 42);
+    assert_eq!(error.msg_lang("zh-CN"), "用户 42 不存在");
+}
+
+#[test]
+fn catalog_supplies_languages_without_compiled_messages() {
+    let _guard = GLOBAL.lock().unwrap_or_else(|e| e.into_inner());
+    set_message_catalog(
+        MessageCatalog::new()
+            .with_message("user_not_found", "ja", "ユーザー {user_id} が見つかりません")
+            .with_message("user_not_found", "es", "Usuario {user_id} no encontrado"),
+    );
+
+    let error = AppError::new(ErrorCode::UserNotFound).with_arg("user_id", 42);
+    assert_eq!(error.msg_lang("ja"), "ユーザー 42 が見つかりません");
+    // 截断子标签、配置的回退链（gl -> es）同样先在目录中查找
+    assert_eq!(error.msg_lang("ja-JP"), "ユーザー 42 が見つかりません");
+    assert_eq!(error.msg_lang("gl"), "Usuario 42 no encontrado");
+    let info = error.info_lang("ja");
+    assert_eq!(info.message, "ユーザー 42 が見つかりません");
+    assert_eq!(info.lang, "en");
+    // 目录中也没有的语言仍然回退到编译时的默认语言
+    assert_eq!(error.msg_lang("fr"), "User 42 not found");
+
+    clear_message_catalog();
+    assert_eq!(error.msg_lang("ja"), "User 42 not found");
+}
+
+#[test]
+fn watched_file_is_reloaded_and_bad_edits_keep_previous_catalog() {
+    let _guard = GLOBAL.lock().unwrap_or_else(|e| e.into_inner());
+    let path = temp_file("messages.yaml");
+    std::fs::write(&path, "user_not_found:\n  en: \"No user {user_id}\"\n").unwrap();
+
+    let watcher = watch_message_catalog(&path).unwrap();
+    let error = AppError::new(ErrorCode::UserNotFound).with_arg("user_id", 7);
+    assert_eq!(error.msg(), "No user 7");
+
+    std::fs::write(&path, "user_not_found:\n  en: \"User #{user_id} is gone\"\n").unwrap();
+    assert!(wait_until(|| error.msg() == "User #7 is gone"), "{}", error.msg());
+
+    std::fs::write(&path, "user_not_found: [").unwrap();
+    assert!(wait_until(|| watcher.last_error().is_some()));
+    assert_eq!(error.msg(), "User #7 is gone");
+
+    drop(watcher);
+    clear_message_catalog();
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn missing_file_is_reported() {
+    let err = watch_message_catalog(temp_file("missing.json")).unwrap_err();
+    assert!(matches!(err, CatalogError::Io { .. }), "{:?}", err);
+}
@@ -0,0 +1,171 @@
+// 🧪 biz-error 命令行工具测试（lint / list / next-code / new / diff）
+#![cfg(feature = "cli")]
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("biz-error-codegen-core/tests/fixtures")
+        .join(name)
+}
+
+fn run(config: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_biz-error"))
+        .arg("--config")
+        .arg(config)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn lint_reports_every_problem_and_fails() {
+    let ok = run(&fixture("categories.yaml"), &["lint"]);
+    assert!(ok.status.success(), "{}", stderr(&ok));
+    assert!(stdout(&ok).contains("4 error codes in 1 file(s), no problems found"));
+
+    // 警告不影响退出码（--deny-warnings 时除外），汇总中给出警告数
+    let warned = run(&fixture("translations/biz_errors.yaml"), &["lint"]);
+    assert!(warned.status.success(), "{}", stderr(&warned));
+    assert!(stdout(&warned).contains("4 error codes in 2 file(s), 5 warnings"), "{}", stdout(&warned));
+    assert!(stderr(&warned).contains("warning: error `user_locked` is missing a `zh-CN` translation"));
+    assert!(!stdout(&warned).contains("no problems found"));
+    let denied = run(&fixture("translations/biz_errors.yaml"), &["lint", "--deny-warnings"]);
+    assert!(!denied.status.success());
+
+    // 只启用 cli feature 也能读取 JSON / TOML 配置和 Fluent 资源
+    for config in ["formats/basic.json", "formats/basic.toml", "fluent/plural.yaml"] {
+        let output = run(&fixture(config), &["lint"]);
+        assert!(output.status.success(), "{}: {}", config, stderr(&output));
+        assert!(stdout(&output).contains("no problems found"), "{}", stdout(&output));
+    }
+
+    let failed = run(&fixture("include/duplicate_code.yaml"), &["lint"]);
+    assert!(!failed.status.success());
+    assert!(stderr(&failed).contains("duplicate code 4000"), "{}", stderr(&failed));
+}
+
+#[test]
+fn list_prints_a_table_sorted_by_code() {
+    let output = run(&fixture("groups.yaml"), &["list", "--lang", "zh-CN"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        stdout(&output),
+        "CODE  HTTP  KEY               CATEGORY  GROUP  MESSAGE
+4004  404   user_not_found    client    user   用户 {user_id} 不存在
+4010  400   invalid_password  client    user   密码错误
+4104  404   not_found         client    order  订单不存在
+5100  500   payment_failed    server    order  支付失败
+"
+    );
+
+    let output = run(&fixture("groups.yaml"), &["list", "--group", "order", "--category", "server"]);
+    assert_eq!(stdout(&output).lines().count(), 2);
+}
+
+#[test]
+fn new_appends_an_entry_with_the_next_free_code() {
+    let dir = std::env::temp_dir().join(format!("biz-error-cli-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let config = dir.join("biz_errors.yaml");
+    std::fs::copy(fixture("categories.yaml"), &config).unwrap();
+
+    let next = run(&config, &["next-code", "auth"]);
+    assert_eq!(stdout(&next), "1002\n");
+
+    let added = run(
+        &config,
+        &["new", "token_expired", "--category", "auth", "--http", "401", "-m", "TOKEN EXPIRED (ttl=0)"],
+    );
+    assert!(added.status.success(), "{}", stderr(&added));
+    assert!(stdout(&added).contains("added `token_expired` (code 1002)"));
+    // `TOKEN EXPIRED (ttl` 不是配置中的语言，整段作为默认语言的消息
+    let content = std::fs::read_to_string(&config).unwrap();
+    assert!(
+        content.contains(
+            "  token_expired:\n    code: 1002\n    http_status: 401\n    message:\n      en: \"TOKEN EXPIRED (ttl=0)\"\n  invalid_param:\n"
+        ),
+        "{}",
+        content
+    );
+    assert!(run(&config, &["lint"]).status.success());
+
+    let duplicate = run(&config, &["new", "token_expired", "--code", "1003", "-m", "X"]);
+    assert!(!duplicate.status.success());
+    assert!(stderr(&duplicate).contains("error `token_expired` already exists"), "{}", stderr(&duplicate));
+
+    let twice = run(&config, &["new", "twice", "--code", "1003", "-m", "en=A", "-m", "B"]);
+    assert!(stderr(&twice).contains("message for `en` is given more than once"), "{}", stderr(&twice));
+
+    // 像语言标签却不是配置中的语言：报错而不是写成默认语言的消息
+    let unknown = run(&config, &["new", "user_locked", "--code", "1003", "-m", "zh-CN=用户已锁定"]);
+    assert!(!unknown.status.success());
+    assert!(
+        stderr(&unknown).contains("unknown language `zh-CN` (expected one of: en)"),
+        "{}",
+        stderr(&unknown)
+    );
+    assert!(!std::fs::read_to_string(&config).unwrap().contains("user_locked"));
+
+    let missing = run(&config, &["new", "no_code"]);
+    assert!(!missing.status.success());
+}
+
+#[test]
+fn new_recognises_every_language_used_in_the_config() {
+    let dir = std::env::temp_dir().join(format!("biz-error-cli-langs-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let config = dir.join("biz_errors.yaml");
+    // 没有声明 supported_languages：消息中出现过的语言同样是配置的语言
+    std::fs::write(
+        &config,
+        "errors:\n  not_found:\n    code: 4004\n    message:\n      en: Not found\n      zh-CN: 未找到\n",
+    )
+    .unwrap();
+
+    let added = run(&config, &["new", "user_locked", "--code", "4005", "-m", "User is locked", "-m", "zh-CN=用户已锁定"]);
+    assert!(added.status.success(), "{}", stderr(&added));
+    assert!(
+        std::fs::read_to_string(&config)
+            .unwrap()
+            .ends_with("  user_locked:\n    code: 4005\n    message:\n      en: \"User is locked\"\n      zh-CN: \"用户已锁定\"\n")
+    );
+
+    let unknown = run(&config, &["new", "order_locked", "--code", "4006", "-m", "X", "-m", "ja=ロック"]);
+    assert!(stderr(&unknown).contains("unknown language `ja` (expected one of: en, zh-CN)"), "{}", stderr(&unknown));
+}
+
+#[test]
+fn diff_lists_changes_and_flags_breaking_ones() {
+    let output = run(&fixture("categories.yaml"), &["diff", fixture("basic.yaml").to_str().unwrap()]);
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        r#"+ not_login (1000)
++ authority_failed (1001)
+~ invalid_param: message[zh-CN] "参数无效" removed
+~ internal_error: message[en] "INTERNAL \"SERVER\" ERROR" -> "INTERNAL SERVER ERROR"
+~ internal_error: message[zh-CN] "服务器内部错误" removed
+- success (0) (breaking)
+- user_not_found (4004) (breaking)
+"#
+    );
+
+    let denied = run(
+        &fixture("categories.yaml"),
+        &["diff", "--deny-breaking", fixture("basic.yaml").to_str().unwrap()],
+    );
+    assert!(!denied.status.success());
+
+    let same = run(&fixture("basic.yaml"), &["diff", fixture("basic.yaml").to_str().unwrap()]);
+    assert_eq!(stdout(&same), "no changes\n");
+}
@@ -0,0 +1,59 @@
+// 🧪 测试共用的手写 ErrorCode 实现（不依赖代码生成）
+
+// 每个测试文件只用到其中一部分
+#![allow(dead_code)]
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    InvalidParam,
+    UserNotFound,
+}
+
+impl biz_error::ErrorCode for ErrorCode {
+    fn code(&self) -> i32 {
+        match self {
+            ErrorCode::InvalidParam => 4000,
+            ErrorCode::UserNotFound => 4004,
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            ErrorCode::InvalidParam => "invalid_param",
+            ErrorCode::UserNotFound => "user_not_found",
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        self.message_lang("en")
+    }
+
+    fn message_lang(&self, lang: &str) -> &'static str {
+        match (self, lang) {
+            (ErrorCode::InvalidParam, "zh-CN") => "参数无效",
+            (ErrorCode::InvalidParam, _) => "INVALID PARAMETER",
+            (ErrorCode::UserNotFound, "zh-CN") => "用户 {user_id} 不存在",
+            (ErrorCode::UserNotFound, _) => "User {user_id} not found",
+        }
+    }
+
+    fn supported_languages() -> &'static [&'static str] {
+        &["en", "zh-CN"]
+    }
+
+    /// 回退链中的语言都没有编译时消息，只有运行时来源（消息目录、`MessageProvider`）能用到
+    fn fallbacks() -> biz_error::lang::Fallbacks {
+        &[("gl", &["es", "pt"])]
+    }
+
+    fn type_base_uri() -> Option<&'static str> {
+        Some("https://errors.example.com")
+    }
+
+    fn http_status_code(&self) -> u16 {
+        match self {
+            ErrorCode::InvalidParam => 400,
+            ErrorCode::UserNotFound => 404,
+        }
+    }
+}
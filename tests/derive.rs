@@ -0,0 +1,42 @@
+// 🧪 #[derive(BizErrorCode)] 测试（不需要 YAML 文件）
+#![cfg(feature = "codegen")]
+
+use biz_error::{AppError, BizErrorCode, ErrorCode as _};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BizErrorCode)]
+#[biz(default_language = "en", supported_languages = ["en", "zh-CN"])]
+enum OrderError {
+    #[biz(code = 4000, http = 400, en = "INVALID PARAMETER", zh_CN = "参数无效")]
+    InvalidParam,
+    #[biz(code = 4404, http = 404, en = "Order {order_id} not found", zh_CN = "订单 {order_id} 不存在")]
+    OrderNotFound,
+}
+
+// 同一模块中的第二个枚举不会与第一个冲突
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BizErrorCode)]
+enum PayError {
+    #[biz(code = 6000, en = "PAYMENT FAILED")]
+    PaymentFailed,
+    #[biz(code = -1, en = "UNKNOWN")]
+    Unknown,
+}
+
+#[test]
+fn derive_generates_trait_impl() {
+    assert_eq!(OrderError::InvalidParam.code(), 4000);
+    assert_eq!(OrderError::InvalidParam.key(), "invalid_param");
+    assert_eq!(OrderError::InvalidParam.message_lang("zh"), "参数无效");
+    assert_eq!(OrderError::supported_languages(), &["en", "zh-CN"]);
+    assert_eq!(OrderError::InvalidParam.to_string(), "[4000] INVALID PARAMETER");
+    assert_eq!(OrderError::ALL_ERROR_CODES, &[OrderError::InvalidParam, OrderError::OrderNotFound]);
+    assert_eq!(PayError::ALL_ERROR_CODES.len(), 2);
+    assert_eq!(PayError::Unknown.code(), -1);
+}
+
+#[test]
+fn derive_generates_template_constructors() {
+    let error: AppError<OrderError> = OrderError::order_not_found(42);
+    assert_eq!(error.msg(), "Order 42 not found");
+    assert_eq!(error.msg_lang("zh-CN"), "订单 42 不存在");
+    assert_eq!(PayError::PaymentFailed.message(), "PAYMENT FAILED");
+}
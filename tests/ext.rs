@@ -0,0 +1,53 @@
+// 🧪 ResultExt / OptionExt 测试
+
+mod common;
+
+use std::error::Error as _;
+
+use biz_error::{AppError, OptionExt, ResultExt};
+use common::ErrorCode;
+use serde_json::json;
+
+fn parse(input: &str) -> Result<u32, std::num::ParseIntError> {
+    input.parse()
+}
+
+#[test]
+fn biz_keeps_original_error_as_source() {
+    let error = parse("abc").biz(ErrorCode::InvalidParam).unwrap_err();
+    assert_eq!(error.error_code(), ErrorCode::InvalidParam);
+    assert_eq!(error.source().unwrap().to_string(), "invalid digit found in string");
+    assert_eq!(parse("7").biz(ErrorCode::InvalidParam).unwrap(), 7);
+}
+
+#[test]
+fn biz_with_attaches_context_and_source() {
+    let error = parse("abc")
+        .biz_with(|e| {
+            AppError::new(ErrorCode::InvalidParam)
+                .with_data(json!({ "input": "abc", "reason": e.to_string() }))
+        })
+        .unwrap_err();
+    assert_eq!(error.data().unwrap()["input"], "abc");
+    assert!(error.source().is_some());
+}
+
+#[test]
+fn biz_accepts_string_errors() {
+    let result: Result<(), String> = Err("connection reset".to_string());
+    let error = result.biz(ErrorCode::InvalidParam).unwrap_err();
+    assert_eq!(error.source().unwrap().to_string(), "connection reset");
+}
+
+#[test]
+fn or_biz_converts_none() {
+    let error = None::<u32>.or_biz(ErrorCode::UserNotFound).unwrap_err();
+    assert_eq!(error.code(), 4004);
+    assert!(error.source().is_none());
+
+    let error = None::<u32>
+        .or_biz_with(|| AppError::new(ErrorCode::UserNotFound).with_arg("user_id", 9))
+        .unwrap_err();
+    assert_eq!(error.msg(), "User 9 not found");
+    assert_eq!(Some(1).or_biz(ErrorCode::UserNotFound).unwrap(), 1);
+}
@@ -0,0 +1,151 @@
+// 🧪 响应格式测试
+#![cfg(feature = "axum")]
+
+mod common;
+
+use axum::Router;
+use axum::body::{Body, to_bytes};
+use axum::http::{Request, StatusCode, header};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use biz_error::{
+    AppError, ErrorInfo, FormatResponseLayer, NegotiateLanguageLayer, ProblemDetailsFormatter,
+    ResponseFormatter, set_response_formatter,
+};
+use common::ErrorCode;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+struct NestedFormatter;
+
+impl ResponseFormatter for NestedFormatter {
+    fn format(&self, error: &ErrorInfo<'_>) -> Value {
+        json!({ "error": { "code": error.code, "message": error.msg(), "details": error.data } })
+    }
+
+    fn content_type(&self) -> &'static str {
+        "application/vnd.error+json"
+    }
+}
+
+fn routes() -> Router {
+    Router::new().route(
+        "/user",
+        get(|| async {
+            Err::<(), _>(
+                AppError::new(ErrorCode::UserNotFound)
+                    .with_arg("user_id", 7)
+                    .with_data(json!({ "user_id": 7 })),
+            )
+        }),
+    )
+}
+
+async fn send(app: Router, accept_language: Option<&str>) -> (StatusCode, String, Value) {
+    send_to(app, "/user", accept_language).await
+}
+
+async fn send_to(app: Router, uri: &str, accept_language: Option<&str>) -> (StatusCode, String, Value) {
+    let mut req = Request::get(uri);
+    if let Some(value) = accept_language {
+        req = req.header(header::ACCEPT_LANGUAGE, value);
+    }
+    let resp = app.oneshot(req.body(Body::empty()).unwrap()).await.unwrap();
+    let status = resp.status();
+    let content_type = resp.headers()[header::CONTENT_TYPE].to_str().unwrap().to_string();
+    let body = to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+    (status, content_type, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn router_formatter_overrides_default() {
+    let app = routes().layer(FormatResponseLayer::new(|error: &ErrorInfo<'_>| {
+        json!({ "errcode": error.code, "errmsg": error.msg() })
+    }));
+
+    let (status, content_type, body) = send(app, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(content_type, "application/json");
+    assert_eq!(body, json!({ "errcode": 4004, "errmsg": "User 7 not found" }));
+}
+
+#[tokio::test]
+async fn router_formatter_sets_content_type() {
+    let app = routes().layer(FormatResponseLayer::new(NestedFormatter));
+
+    let (_, content_type, body) = send(app, None).await;
+    assert_eq!(content_type, "application/vnd.error+json");
+    assert_eq!(
+        body,
+        json!({ "error": { "code": 4004, "message": "User 7 not found", "details": { "user_id": 7 } } })
+    );
+}
+
+#[tokio::test]
+async fn formatter_and_language_layers_compose_in_any_order() {
+    let inner_format = routes()
+        .layer(FormatResponseLayer::new(NestedFormatter))
+        .layer(NegotiateLanguageLayer::for_error_code::<ErrorCode>());
+    let outer_format = routes()
+        .layer(NegotiateLanguageLayer::for_error_code::<ErrorCode>())
+        .layer(FormatResponseLayer::new(NestedFormatter));
+
+    for app in [inner_format, outer_format] {
+        let (_, _, body) = send(app, Some("zh-CN")).await;
+        assert_eq!(body["error"]["message"], "用户 7 不存在");
+    }
+}
+
+#[tokio::test]
+async fn nested_router_formatter_wins_over_app_wide_layer() {
+    let app = routes()
+        .nest(
+            "/api",
+            routes()
+                .layer(FormatResponseLayer::new(NestedFormatter))
+                .layer(NegotiateLanguageLayer::for_error_code::<ErrorCode>()),
+        )
+        .layer(FormatResponseLayer::new(|error: &ErrorInfo<'_>| {
+            json!({ "errcode": error.code, "errmsg": error.msg() })
+        }));
+
+    let (_, content_type, body) = send_to(app.clone(), "/api/user", Some("zh-CN")).await;
+    assert_eq!(content_type, "application/vnd.error+json");
+    assert_eq!(body["error"]["message"], "用户 7 不存在");
+
+    let (_, content_type, body) = send(app, None).await;
+    assert_eq!(content_type, "application/json");
+    assert_eq!(body, json!({ "errcode": 4004, "errmsg": "User 7 not found" }));
+}
+
+#[tokio::test]
+async fn global_formatter_applies_without_layer() {
+    set_response_formatter(|error: &ErrorInfo<'_>| json!({ "errcode": error.code, "lang": error.lang }));
+
+    let resp = AppError::new(ErrorCode::InvalidParam).into_response();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body, json!({ "errcode": 4000, "lang": "en" }));
+}
+
+#[tokio::test]
+async fn problem_details_follow_rfc_9457() {
+    let app = routes()
+        .layer(FormatResponseLayer::new(ProblemDetailsFormatter::new()))
+        .layer(NegotiateLanguageLayer::for_error_code::<ErrorCode>());
+
+    let (status, content_type, body) = send(app, Some("zh-CN")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(content_type, "application/problem+json");
+    assert_eq!(
+        body,
+        json!({
+            "type": "https://errors.example.com/user_not_found",
+            "title": "用户 7 不存在",
+            "status": 404,
+            "code": 4004,
+            "user_id": 7
+        })
+    );
+}
@@ -0,0 +1,189 @@
+// 🧪 生成代码的行为测试（使用 codegen 的 golden 测试用例配置）
+#![cfg(feature = "codegen")]
+
+use biz_error::{ErrorCode as _, generate_error_codes};
+
+#[generate_error_codes("biz-error-codegen-core/tests/fixtures/basic.yaml")]
+mod basic {}
+
+#[generate_error_codes("biz-error-codegen-core/tests/fixtures/categories.yaml")]
+mod categories {}
+
+#[generate_error_codes("biz-error-codegen-core/tests/fixtures/templates.yaml")]
+mod templates {}
+
+#[cfg(feature = "codegen-json")]
+#[generate_error_codes("biz-error-codegen-core/tests/fixtures/formats/basic.json")]
+mod basic_json {}
+
+#[cfg(feature = "codegen-toml")]
+#[generate_error_codes("biz-error-codegen-core/tests/fixtures/formats/basic.toml")]
+mod basic_toml {}
+
+/// 模块的可见性、属性和手写内容与生成的代码放在一起
+#[generate_error_codes("biz-error-codegen-core/tests/fixtures/basic.yaml")]
+pub mod extended {
+    #![allow(dead_code)]
+
+    impl ErrorCode {
+        /// 是否为成功
+        pub fn is_success(&self) -> bool {
+            self.code() == 0
+        }
+    }
+
+    /// 所有 4xxx 错误码
+    pub fn client_errors() -> Vec<ErrorCode> {
+        ALL_ERROR_CODES
+            .iter()
+            .copied()
+            .filter(|code| (4000..5000).contains(&code.code()))
+            .collect()
+    }
+}
+
+#[generate_error_codes("biz-error-codegen-core/tests/fixtures/groups.yaml")]
+mod groups {}
+
+#[generate_error_codes("biz-error-codegen-core/tests/fixtures/include/root.yaml")]
+mod included {}
+
+#[generate_error_codes("biz-error-codegen-core/tests/fixtures/include/errors/*.yaml")]
+mod globbed {}
+
+#[cfg(feature = "fluent")]
+#[generate_error_codes("biz-error-codegen-core/tests/fixtures/fluent/plural.yaml")]
+mod plural {}
+
+#[test]
+fn basic_codes_and_messages() {
+    use basic::ErrorCode;
+
+    assert_eq!(ErrorCode::InvalidParam.code(), 4000);
+    assert_eq!(ErrorCode::InvalidParam.message(), "INVALID PARAMETER");
+    assert_eq!(ErrorCode::InvalidParam.message_lang("zh-CN"), "参数无效");
+    assert_eq!(basic::ALL_ERROR_CODES.len(), 4);
+}
+
+#[cfg(all(feature = "codegen-json", feature = "codegen-toml"))]
+#[test]
+fn json_and_toml_configs_match_yaml() {
+    for (code, json, toml) in [
+        (basic::ErrorCode::InvalidParam, basic_json::ErrorCode::InvalidParam, basic_toml::ErrorCode::InvalidParam),
+        (basic::ErrorCode::InternalError, basic_json::ErrorCode::InternalError, basic_toml::ErrorCode::InternalError),
+    ] {
+        assert_eq!((json.code(), json.message_lang("zh-CN")), (code.code(), code.message_lang("zh-CN")));
+        assert_eq!((toml.code(), toml.message()), (code.code(), code.message()));
+    }
+}
+
+#[test]
+fn module_items_are_kept_alongside_generated_code() {
+    use extended::ErrorCode;
+
+    assert!(ErrorCode::Success.is_success());
+    assert!(!ErrorCode::InternalError.is_success());
+    assert_eq!(extended::client_errors(), [ErrorCode::InvalidParam, ErrorCode::UserNotFound]);
+}
+
+#[test]
+fn included_files_merge_into_one_enum() {
+    use included::ErrorCode;
+
+    assert_eq!(included::ALL_ERROR_CODES.len(), 4);
+    assert_eq!(ErrorCode::Success.code(), 0);
+    assert_eq!(ErrorCode::UserNotFound.message_lang("zh-CN"), "用户不存在");
+    assert_eq!(ErrorCode::InternalError.message(), basic::ErrorCode::InternalError.message());
+
+    assert_eq!(globbed::ALL_ERROR_CODES.len(), 3);
+    assert_eq!(globbed::ErrorCode::InvalidParam.code(), 4000);
+}
+
+#[test]
+fn categories_are_generated() {
+    use categories::{Category, ErrorCode};
+
+    assert_eq!(ErrorCode::NotLogin.category(), Category::Auth);
+    assert_eq!(ErrorCode::InvalidParam.category().name(), "param");
+    assert_eq!(Category::Auth.range(), 1000..=1999);
+    assert_eq!(categories::ALL_CATEGORIES.len(), 3);
+}
+
+#[test]
+fn templates_render_with_typed_constructors() {
+    use templates::ErrorCode;
+
+    let error = ErrorCode::quota_exceeded(120, 100);
+    assert_eq!(error.msg(), "Quota exceeded: 120/100");
+    assert_eq!(error.msg_lang("zh-CN"), "超出配额：120/100");
+    assert_eq!(ErrorCode::user_not_found("u-1").msg(), "User u-1 not found");
+    assert_eq!(biz_error::AppError::new(ErrorCode::LiteralBraces).msg(), "Expected {json}");
+}
+
+#[test]
+fn groups_generate_domain_enums_and_umbrella() {
+    use biz_error::AppError;
+    use groups::{Category, ErrorCode, OrderError, UserError};
+
+    assert_eq!(UserError::ALL_ERROR_CODES.len(), 2);
+    assert_eq!(OrderError::NotFound.message_lang("zh-CN"), "订单不存在");
+    assert_eq!(OrderError::PaymentFailed.category(), Category::Server);
+
+    let code = ErrorCode::from(OrderError::NotFound);
+    assert_eq!(code, ErrorCode::Order(OrderError::NotFound));
+    assert_eq!((code.code(), code.key(), code.http_status_code()), (4104, "not_found", 404));
+    assert_eq!(code.message_lang("zh-CN"), "订单不存在");
+    assert_eq!(code.category(), Category::Client);
+    assert_eq!(groups::ALL_ERROR_CODES.len(), 4);
+    assert_eq!(ErrorCode::supported_languages(), UserError::supported_languages());
+
+    let error: AppError<ErrorCode> = UserError::user_not_found(42).with_msg("gone").into_code();
+    assert_eq!(error.error_code(), ErrorCode::User(UserError::UserNotFound));
+    assert_eq!(error.msg(), "gone");
+    assert_eq!(error.msg_lang("zh-CN"), "gone");
+    assert_eq!(error.args(), [("user_id", "42".to_string())]);
+}
+
+#[test]
+fn keys_and_type_base_uri_are_generated() {
+    use templates::ErrorCode;
+
+    assert_eq!(ErrorCode::QuotaExceeded.key(), "quota_exceeded");
+    assert_eq!(ErrorCode::type_base_uri(), Some("https://errors.example.com/"));
+    assert_eq!(basic::ErrorCode::type_base_uri(), None);
+}
+
+#[test]
+fn languages_resolve_through_prefixes_and_fallbacks() {
+    use templates::ErrorCode;
+
+    let code = ErrorCode::UserNotFound;
+    assert_eq!(code.resolve_lang("zh"), "zh-CN");
+    assert_eq!(code.resolve_lang("en-GB"), "en");
+    assert_eq!(code.resolve_lang("zh-HK"), "zh-CN");
+    assert_eq!(code.resolve_lang("ja-JP"), "zh-CN");
+    assert_eq!(code.resolve_lang("fr"), "en");
+    assert_eq!(code.message_lang("zh-Hans-CN"), "用户 {user_id} 不存在");
+}
+
+#[cfg(feature = "fluent")]
+#[test]
+fn fluent_messages_pick_plural_forms() {
+    use plural::ErrorCode;
+
+    let files = |count: u32| ErrorCode::files_remaining(count);
+    assert_eq!(files(1).msg_lang("ru"), "Остался 1 файл");
+    assert_eq!(files(3).msg_lang("ru"), "Осталось 3 файла");
+    assert_eq!(files(5).msg_lang("ru"), "Осталось 5 файлов");
+    assert_eq!(files(21).msg_lang("ru"), "Остался 21 файл");
+    assert_eq!(files(1).msg(), "1 file left");
+    assert_eq!(files(2).msg(), "2 files left");
+
+    // message_lang 返回扁平化的默认变体
+    assert_eq!(ErrorCode::FilesRemaining.message_lang("ru"), "Осталось {count} файлов");
+    assert_eq!(
+        ErrorCode::user_not_found(7).msg_lang("ru-RU"),
+        "Сервис: пользователь 7 не найден"
+    );
+    assert_eq!(ErrorCode::user_not_found(7).msg(), "User 7 not found");
+}
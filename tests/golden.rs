@@ -0,0 +1,156 @@
+// 🧪 Golden 一致性测试 - 过程宏展开的代码与 build.rs 生成的 golden 文件行为一致
+//
+// 同一份夹具分别经 `#[generate_error_codes]` 展开、经 `include!` 引入对应的 `tests/golden/<name>.rs`，
+// 逐个错误码比较变体、错误码、HTTP 状态码、各语言的消息等；
+// 并检查 `render` 的输出与 golden 文件逐字节相同（宏展开与 `generate` 的 token 一致性见
+// `biz-error-codegen-core/tests/golden.rs`）。
+#![cfg(feature = "codegen")]
+
+use std::fmt::Debug;
+
+use biz_error::ErrorCode;
+
+mod basic {
+    #[biz_error::generate_error_codes("biz-error-codegen-core/tests/fixtures/basic.yaml")]
+    pub mod expanded {}
+
+    #[allow(dead_code)]
+    pub mod built {
+        include!("../biz-error-codegen-core/tests/golden/basic.rs");
+    }
+}
+
+mod categories {
+    #[biz_error::generate_error_codes("biz-error-codegen-core/tests/fixtures/categories.yaml")]
+    pub mod expanded {}
+
+    #[allow(dead_code)]
+    pub mod built {
+        include!("../biz-error-codegen-core/tests/golden/categories.rs");
+    }
+}
+
+mod groups {
+    #[biz_error::generate_error_codes("biz-error-codegen-core/tests/fixtures/groups.yaml")]
+    pub mod expanded {}
+
+    #[allow(dead_code)]
+    pub mod built {
+        include!("../biz-error-codegen-core/tests/golden/groups.rs");
+    }
+}
+
+mod templates {
+    #[biz_error::generate_error_codes("biz-error-codegen-core/tests/fixtures/templates.yaml")]
+    pub mod expanded {}
+
+    #[allow(dead_code)]
+    pub mod built {
+        include!("../biz-error-codegen-core/tests/golden/templates.rs");
+    }
+}
+
+/// 覆盖精确匹配、前缀匹配和未知语言（回退到默认语言）
+const LANGS: &[&str] = &["en", "zh-CN", "zh", "zh-Hans-CN", "ja", "fr"];
+
+fn assert_same<A: ErrorCode, B: ErrorCode>(expanded: &[A], built: &[B]) {
+    assert_eq!(expanded.len(), built.len());
+    assert_eq!(A::supported_languages(), B::supported_languages());
+    assert_eq!(A::default_language(), B::default_language());
+    assert_eq!(A::fallbacks(), B::fallbacks());
+    assert_eq!(A::type_base_uri(), B::type_base_uri());
+
+    for (a, b) in expanded.iter().zip(built) {
+        assert_eq!(format!("{:?}", a), format!("{:?}", b));
+        assert_eq!(a.code(), b.code(), "{:?}", a);
+        assert_eq!(a.key(), b.key(), "{:?}", a);
+        assert_eq!(a.http_status_code(), b.http_status_code(), "{:?}", a);
+        assert_eq!(a.message(), b.message(), "{:?}", a);
+        for lang in LANGS {
+            assert_eq!(a.resolve_lang(lang), b.resolve_lang(lang), "{:?} [{}]", a, lang);
+            assert_eq!(a.message_lang(lang), b.message_lang(lang), "{:?} [{}]", a, lang);
+        }
+    }
+}
+
+fn assert_same_categories<A: Debug, B: Debug>(
+    expanded: &[A],
+    built: &[B],
+    describe_a: impl Fn(&A) -> (&'static str, std::ops::RangeInclusive<i32>),
+    describe_b: impl Fn(&B) -> (&'static str, std::ops::RangeInclusive<i32>),
+) {
+    assert_eq!(expanded.len(), built.len());
+    for (a, b) in expanded.iter().zip(built) {
+        assert_eq!(format!("{:?}", a), format!("{:?}", b));
+        assert_eq!(describe_a(a), describe_b(b));
+    }
+}
+
+#[test]
+fn render_matches_golden_files_byte_for_byte() {
+    let goldens = [
+        ("basic", include_str!("../biz-error-codegen-core/tests/golden/basic.rs")),
+        ("categories", include_str!("../biz-error-codegen-core/tests/golden/categories.rs")),
+        ("groups", include_str!("../biz-error-codegen-core/tests/golden/groups.rs")),
+        ("templates", include_str!("../biz-error-codegen-core/tests/golden/templates.rs")),
+    ];
+    for (name, golden) in goldens {
+        let path = format!("biz-error-codegen-core/tests/fixtures/{}.yaml", name);
+        let catalog = biz_error_codegen_core::ErrorCatalog::from_path(path).unwrap();
+        assert_eq!(biz_error_codegen_core::render(&catalog).unwrap(), golden, "{}", name);
+    }
+}
+
+#[test]
+fn basic_matches_golden() {
+    assert_same(basic::expanded::ALL_ERROR_CODES, basic::built::ALL_ERROR_CODES);
+}
+
+#[test]
+fn categories_match_golden() {
+    use categories::{built, expanded};
+
+    assert_same(expanded::ALL_ERROR_CODES, built::ALL_ERROR_CODES);
+    assert_same_categories(
+        expanded::ALL_CATEGORIES,
+        built::ALL_CATEGORIES,
+        |c| (c.name(), c.range()),
+        |c| (c.name(), c.range()),
+    );
+    for (a, b) in expanded::ALL_ERROR_CODES.iter().zip(built::ALL_ERROR_CODES) {
+        assert_eq!(format!("{:?}", a.category()), format!("{:?}", b.category()));
+    }
+}
+
+#[test]
+fn groups_match_golden() {
+    use groups::{built, expanded};
+
+    assert_same(expanded::ALL_ERROR_CODES, built::ALL_ERROR_CODES);
+    assert_same(expanded::UserError::ALL_ERROR_CODES, built::UserError::ALL_ERROR_CODES);
+    assert_same(expanded::OrderError::ALL_ERROR_CODES, built::OrderError::ALL_ERROR_CODES);
+    assert_same_categories(
+        expanded::ALL_CATEGORIES,
+        built::ALL_CATEGORIES,
+        |c| (c.name(), c.range()),
+        |c| (c.name(), c.range()),
+    );
+    for (a, b) in expanded::ALL_ERROR_CODES.iter().zip(built::ALL_ERROR_CODES) {
+        assert_eq!(format!("{:?}", a.category()), format!("{:?}", b.category()));
+    }
+}
+
+#[test]
+fn templates_match_golden() {
+    use templates::{built, expanded};
+
+    assert_same(expanded::ALL_ERROR_CODES, built::ALL_ERROR_CODES);
+    assert_eq!(
+        expanded::ErrorCode::quota_exceeded(3u32, 5u32).msg_lang("zh-CN"),
+        built::ErrorCode::quota_exceeded(3u32, 5u32).msg_lang("zh-CN"),
+    );
+    assert_eq!(
+        expanded::ErrorCode::user_not_found(42).to_response().msg,
+        built::ErrorCode::user_not_found(42).to_response().msg,
+    );
+}
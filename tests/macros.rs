@@ -0,0 +1,74 @@
+// 🧪 biz_bail! / biz_ensure! 测试
+
+mod common;
+
+use biz_error::{AppError, biz_bail, biz_ensure};
+use common::ErrorCode;
+use serde_json::json;
+
+fn bail(variant: u8, id: u64) -> Result<(), AppError<ErrorCode>> {
+    match variant {
+        0 => biz_bail!(ErrorCode::UserNotFound),
+        1 => biz_bail!(ErrorCode::InvalidParam, "user id {} invalid", id),
+        2 => biz_bail!(ErrorCode::InvalidParam, data = json!({ "id": id })),
+        _ => biz_bail!(ErrorCode::InvalidParam, data = json!({ "id": id }), "user id {id} invalid"),
+    }
+}
+
+#[test]
+fn bail_builds_error_with_message_and_data() {
+    let error = bail(0, 7).unwrap_err();
+    assert_eq!(error.error_code(), ErrorCode::UserNotFound);
+    assert!(error.data().is_none());
+
+    let error = bail(1, 7).unwrap_err();
+    assert_eq!(error.msg(), "user id 7 invalid");
+    assert!(error.data().is_none());
+
+    let error = bail(2, 7).unwrap_err();
+    assert_eq!(error.msg(), "INVALID PARAMETER");
+    assert_eq!(error.data(), Some(&json!({ "id": 7 })));
+
+    let error = bail(3, 7).unwrap_err();
+    assert_eq!(error.msg(), "user id 7 invalid");
+    assert_eq!(error.data(), Some(&json!({ "id": 7 })));
+}
+
+fn withdraw(amount: u64, balance: u64) -> Result<u64, AppError<ErrorCode>> {
+    biz_ensure!(amount > 0, ErrorCode::InvalidParam, "amount must be positive");
+    biz_ensure!(
+        amount <= balance,
+        ErrorCode::InvalidParam,
+        data = json!({ "amount": amount, "balance": balance }),
+    );
+    Ok(balance - amount)
+}
+
+#[test]
+fn ensure_returns_early_only_when_condition_fails() {
+    assert_eq!(withdraw(30, 100).unwrap(), 70);
+    assert_eq!(withdraw(0, 100).unwrap_err().msg(), "amount must be positive");
+    assert_eq!(
+        withdraw(300, 100).unwrap_err().data(),
+        Some(&json!({ "amount": 300, "balance": 100 }))
+    );
+}
+
+#[derive(Debug)]
+struct ServiceError(i32);
+
+impl From<AppError<ErrorCode>> for ServiceError {
+    fn from(error: AppError<ErrorCode>) -> Self {
+        ServiceError(error.code())
+    }
+}
+
+fn service(id: u64) -> Result<u64, ServiceError> {
+    biz_ensure!(id != 0, ErrorCode::InvalidParam);
+    Ok(id)
+}
+
+#[test]
+fn bail_converts_into_caller_error_type() {
+    assert_eq!(service(0).unwrap_err().0, 4000);
+}
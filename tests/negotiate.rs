@@ -0,0 +1,62 @@
+// 🧪 axum 语言协商测试
+#![cfg(feature = "axum")]
+
+mod common;
+
+use axum::body::{Body, to_bytes};
+use axum::http::{Request, StatusCode, header};
+use axum::Router;
+use axum::routing::get;
+use biz_error::{AppError, Lang, NegotiateLanguageLayer};
+use common::ErrorCode;
+use serde_json::Value;
+use tower::ServiceExt;
+
+fn app() -> Router {
+    Router::new()
+        .route(
+            "/user",
+            get(|| async {
+                Err::<(), _>(AppError::new(ErrorCode::UserNotFound).with_arg("user_id", 7))
+            }),
+        )
+        .route("/lang", get(|Lang(lang): Lang| async move { lang }))
+        .layer(NegotiateLanguageLayer::for_error_code::<ErrorCode>())
+}
+
+async fn request(uri: &str, accept_language: Option<&str>) -> (StatusCode, Vec<u8>) {
+    let mut req = Request::get(uri);
+    if let Some(value) = accept_language {
+        req = req.header(header::ACCEPT_LANGUAGE, value);
+    }
+    let resp = app().oneshot(req.body(Body::empty()).unwrap()).await.unwrap();
+    let status = resp.status();
+    let body = to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+    (status, body.to_vec())
+}
+
+async fn error_msg(accept_language: Option<&str>) -> String {
+    let (status, body) = request("/user", accept_language).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    json["msg"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn error_renders_in_negotiated_language() {
+    assert_eq!(error_msg(Some("zh-CN")).await, "用户 7 不存在");
+    assert_eq!(error_msg(Some("fr, zh;q=0.5")).await, "用户 7 不存在");
+    assert_eq!(error_msg(Some("zh-CN;q=0.2, en-GB;q=0.9")).await, "User 7 not found");
+}
+
+#[tokio::test]
+async fn error_falls_back_to_default_language() {
+    assert_eq!(error_msg(None).await, "User 7 not found");
+    assert_eq!(error_msg(Some("fr")).await, "User 7 not found");
+}
+
+#[tokio::test]
+async fn handler_can_extract_negotiated_language() {
+    let (_, body) = request("/lang", Some("zh-Hans-CN")).await;
+    assert_eq!(body, b"zh-CN");
+}
@@ -0,0 +1,130 @@
+// 🧪 MessageProvider 测试（全局提供者、缓存包装、SQLite 参考实现）
+
+mod common;
+
+use std::borrow::Cow;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use biz_error::{
+    AppError, CachedMessageProvider, MessageProvider, clear_message_provider, set_message_provider,
+};
+use common::ErrorCode;
+
+/// 全局消息提供者在同一个测试进程内共享，修改它的测试串行执行
+static GLOBAL: Mutex<()> = Mutex::new(());
+
+/// 只提供 4004 的中文消息，并记录查询次数
+#[derive(Default)]
+struct Counting {
+    calls: AtomicUsize,
+}
+
+impl MessageProvider for Counting {
+    fn message(&self, code: i32, lang: &str) -> Option<Cow<'_, str>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        (code == 4004 && lang == "zh-CN").then_some(Cow::Borrowed("找不到用户 {user_id}"))
+    }
+}
+
+impl Counting {
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[test]
+fn provider_overrides_generated_messages_and_falls_back() {
+    let _guard = GLOBAL.lock().unwrap_or_else(|e| e.into_inner());
+    set_message_provider(Counting::default());
+
+    let error = AppError::new(ErrorCode::UserNotFound).with_arg("user_id", 42);
+    assert_eq!(error.msg_lang("zh-CN"), "找不到用户 42");
+    assert_eq!(error.info_lang("zh").message, "找不到用户 42");
+    assert_eq!(error.msg(), "User 42 not found");
+    assert_eq!(AppError::new(ErrorCode::InvalidParam).msg_lang("zh-CN"), "参数无效");
+
+    clear_message_provider();
+    assert_eq!(error.msg_lang("zh-CN"), "用户 42 不存在");
+}
+
+#[test]
+fn cache_serves_hits_and_misses_until_expired() {
+    let cached = CachedMessageProvider::new(Counting::default(), Duration::from_millis(50), 16);
+
+    assert_eq!(cached.message(4004, "zh-CN").as_deref(), Some("找不到用户 {user_id}"));
+    assert_eq!(cached.message(4004, "zh-CN").as_deref(), Some("找不到用户 {user_id}"));
+    // 没有消息的结果同样会缓存
+    assert_eq!(cached.message(4000, "zh-CN"), None);
+    assert_eq!(cached.message(4000, "zh-CN"), None);
+    assert_eq!(cached.inner().calls(), 2);
+    assert_eq!(cached.cached_len(), 2);
+
+    std::thread::sleep(Duration::from_millis(60));
+    cached.message(4004, "zh-CN");
+    assert_eq!(cached.inner().calls(), 3);
+    assert_eq!(cached.cached_len(), 2);
+
+    cached.clear();
+    assert_eq!(cached.cached_len(), 0);
+    cached.message(4004, "zh-CN");
+    assert_eq!(cached.inner().calls(), 4);
+}
+
+#[test]
+fn cache_evicts_oldest_entries_beyond_capacity() {
+    let cached = CachedMessageProvider::new(Counting::default(), Duration::from_secs(60), 2);
+    cached.message(1, "en");
+    cached.message(2, "en");
+    cached.message(3, "en");
+    assert_eq!(cached.cached_len(), 2);
+    assert_eq!(cached.inner().calls(), 3);
+
+    // 3 仍在缓存中，1 已被淘汰
+    cached.message(3, "en");
+    assert_eq!(cached.inner().calls(), 3);
+    cached.message(1, "en");
+    assert_eq!(cached.inner().calls(), 4);
+    assert_eq!(cached.cached_len(), 2);
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn sqlite_provider_with_cache() {
+    use biz_error::SqliteMessageProvider;
+
+    let _guard = GLOBAL.lock().unwrap_or_else(|e| e.into_inner());
+    let sqlite = SqliteMessageProvider::open_in_memory().unwrap();
+    sqlite.create_table().unwrap();
+    sqlite.set_message(4004, "zh-CN", "找不到用户 {user_id}").unwrap();
+    sqlite.set_message(4004, "zh-CN", "用户 {user_id} 已注销").unwrap();
+    assert_eq!(sqlite.message(4004, "zh-CN").as_deref(), Some("用户 {user_id} 已注销"));
+
+    set_message_provider(CachedMessageProvider::new(sqlite, Duration::from_secs(60), 128));
+    let error = AppError::new(ErrorCode::UserNotFound).with_arg("user_id", 42);
+    assert_eq!(error.msg_lang("zh-CN"), "用户 42 已注销");
+    assert_eq!(error.msg_lang("en"), "User 42 not found");
+    clear_message_provider();
+
+    // 数据库可以提供编译时没有的语言：先查请求的语言，再查回退链（gl -> es）和截断后的子标签
+    let sqlite = SqliteMessageProvider::open_in_memory().unwrap();
+    sqlite.create_table().unwrap();
+    sqlite.set_message(4004, "ja", "ユーザー {user_id} が見つかりません").unwrap();
+    sqlite.set_message(4004, "es", "Usuario {user_id} no encontrado").unwrap();
+    set_message_provider(sqlite);
+    assert_eq!(error.msg_lang("ja"), "ユーザー 42 が見つかりません");
+    assert_eq!(error.msg_lang("ja-JP"), "ユーザー 42 が見つかりません");
+    assert_eq!(error.msg_lang("gl"), "Usuario 42 no encontrado");
+    assert_eq!(error.info_lang("ja").message, "ユーザー 42 が見つかりません");
+    assert_eq!(error.msg_lang("fr"), "User 42 not found");
+    clear_message_provider();
+
+    let sqlite = SqliteMessageProvider::open_in_memory().unwrap();
+    // 表不存在等查询错误视为没有消息
+    assert_eq!(sqlite.message(4004, "zh-CN"), None);
+    sqlite.create_table().unwrap();
+    sqlite.set_message(4004, "en", "gone").unwrap();
+    sqlite.remove_message(4004, "en").unwrap();
+    assert_eq!(sqlite.message(4004, "en"), None);
+}